@@ -6,7 +6,7 @@ use crate::{
 	Event as SuperEvent, State as SuperState,
 };
 use seed::prelude::{AsAtValue, At, El, Ev, IndexMap, Node, Orders, UpdateEl};
-use uywi::{Concept, Length, Page, Script};
+use uywi::{Concept, Length, Page, Script, MAX_LENGTH};
 use web_sys::{FormData, HtmlFormElement, HtmlInputElement};
 
 /// State of the dictionary.
@@ -127,6 +127,10 @@ impl State {
 						class!["td"],
 						select![
 							attrs![At::Name => "input"],
+							option![
+								attrs![At::Value => Length::L1, At::Selected => (Length::L1 == self.length).as_at_value()],
+								Length::L1.to_string()
+							],
 							option![
 								attrs![At::Value => Length::L2, At::Selected => (Length::L2 == self.length).as_at_value()],
 								Length::L2.to_string()
@@ -139,6 +143,10 @@ impl State {
 								attrs![At::Value => Length::L4, At::Selected => (Length::L4 == self.length).as_at_value()],
 								Length::L4.to_string()
 							],
+							option![
+								attrs![At::Value => Length::L5, At::Selected => (Length::L5 == self.length).as_at_value()],
+								Length::L5.to_string()
+							],
 						],
 					],
 					div![class!["td"], button![attrs![At::Type => "submit", At::Name => "load"], "Load"]]
@@ -174,8 +182,9 @@ impl State {
 								At::Name => "input",
 								At::Size => 1,
 								At::Required => true.as_at_value(),
-								At::MinLength => 2,
-								At::MaxLength => 4,
+								// UYWI Chiffre writes every radical as one character
+								At::MinLength => Length::all()[0].as_int(),
+								At::MaxLength => MAX_LENGTH,
 								At::AutoComplete => "off",
 								At::SpellCheck => "false",
 								At::Custom("autocorrect".into()) => "off",
@@ -221,6 +230,8 @@ impl State {
 				self.script = match data {
 					0 => Script::UywiChiffre,
 					1 => Script::IpaPeter,
+					2 => Script::XSampa,
+					3 => Script::Latin,
 					_ => unreachable!("invalid script"),
 				}
 			},
@@ -327,6 +338,14 @@ fn build_script(state: &State) -> Node<SuperEvent> {
 					attrs![At::Value => 1, At::Selected => (Script::IpaPeter == state.script).as_at_value()],
 					Script::IpaPeter.to_string()
 				],
+				option![
+					attrs![At::Value => 2, At::Selected => (Script::XSampa == state.script).as_at_value()],
+					Script::XSampa.to_string()
+				],
+				option![
+					attrs![At::Value => 3, At::Selected => (Script::Latin == state.script).as_at_value()],
+					Script::Latin.to_string()
+				],
 			],
 		],
 		div![class!["td"], button![attrs![At::Type => "submit", At::Name => "load"], "Load"]]
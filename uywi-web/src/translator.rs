@@ -6,28 +6,36 @@ use crate::{
 	Event as SuperEvent, State as SuperState,
 };
 use seed::prelude::{AsAtValue, At, El, Ev, IndexMap, Node, Orders, St, UpdateEl};
-use uywi::Script;
+use uywi::{transliterate, Script, ScriptRegistry};
 use web_sys::{FormData, HtmlFormElement, HtmlTextAreaElement};
 
 /// State of the translator.
-#[derive(Default)]
-pub struct State;
+pub struct State {
+	/// Script to translate from.
+	from: Script,
+	/// Script to translate to.
+	to: Script,
+}
 
 impl State {
 	/// Drawing translator.
-	#[allow(clippy::unused_self)]
 	pub(crate) fn view(&self) -> Vec<Node<SuperEvent>> {
-		use seed::{attrs, button, class, div, form, prelude::ev, style, textarea};
+		use seed::{attrs, button, class, div, form, prelude::ev, select, style, textarea};
 
 		return vec![form![
 			class!["center"],
-			style![St::GridTemplateRows => "min-content auto auto min-content", St::GridRowGap => "1em"],
+			style![St::GridTemplateRows => "min-content min-content auto auto min-content", St::GridRowGap => "1em"],
 			util::submit_ev(Event::Translate),
 			div![button![
 				attrs![At::Type => "button"],
 				ev(Ev::Click, |_| return Event::Home.into()),
 				"Back to home"
 			]],
+			div![
+				select![attrs![At::Name => "from"], build_scripts(self.from)],
+				" to ",
+				select![attrs![At::Name => "to"], build_scripts(self.to)],
+			],
 			div![
 				style![St::JustifySelf => "stretch", St::AlignSelf => "stretch"],
 				textarea![attrs![
@@ -52,37 +60,18 @@ impl State {
 	}
 
 	/// Handling events.
-	#[allow(clippy::unused_self)]
 	pub(crate) fn update(&mut self, event: Event, _orders: &mut impl Orders<SuperEvent>) -> Option<SuperState> {
 		match event {
 			Event::Home => return Some(SuperState::Home(Home::default())),
 			Event::Translate(form, data) => {
-				let mut output = String::new();
-				let mut word = String::new();
+				// set scripts
+				let registry = ScriptRegistry::new();
+				self.from = registry.get(&data.pget("from")).expect("script input is invalid");
+				self.to = registry.get(&data.pget("to")).expect("script input is invalid");
 
-				let handle_word = |output: &mut String, word: &mut String| {
-					if !word.is_empty() {
-						if let Ok(word) = Script::UywiChiffre.from_str(word) {
-							output.push_str(&word.to_string(Script::IpaPeter));
-						} else {
-							output.push_str(word);
-						}
-					}
-				};
+				let output = transliterate(&data.pget("input"), self.from, self.to);
 
-				for char in data.pget("input").chars() {
-					if char.is_whitespace() {
-						handle_word(&mut output, &mut word);
-						word.clear();
-						output.push(char);
-					} else {
-						word.push(char);
-					}
-				}
-
-				handle_word(&mut output, &mut word);
-
-				form.pget::<HtmlTextAreaElement>("output").set_value(&output);
+				form.pget::<HtmlTextAreaElement>("output").set_value(output.text());
 			},
 		};
 
@@ -90,6 +79,15 @@ impl State {
 	}
 }
 
+impl Default for State {
+	fn default() -> Self {
+		return Self {
+			from: Script::UywiChiffre,
+			to: Script::IpaPeter,
+		};
+	}
+}
+
 /// Translator events.
 #[derive(Debug, Clone)]
 pub enum Event {
@@ -98,3 +96,18 @@ pub enum Event {
 	/// Translate!
 	Translate(HtmlFormElement, FormData),
 }
+
+/// Build options to select a script, `selected` is selected.
+fn build_scripts(selected: Script) -> Vec<Node<SuperEvent>> {
+	use seed::{attrs, option};
+
+	return ScriptRegistry::new()
+		.iter()
+		.map(|script| {
+			return option![
+				attrs![At::Value => script.to_string(), At::Selected => (script == selected).as_at_value()],
+				script.to_string()
+			];
+		})
+		.collect();
+}
@@ -0,0 +1,75 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(clippy::needless_return, clippy::missing_inline_in_public_items, clippy::result_expect_used)]
+
+//! Benchmarks for iterating over [`Concept`]s and calculating their index.
+//!
+//! `ranking` spreads its indexes over all of [`Length::L5`], so they don't profit from neighbouring concepts sharing radicals.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use uywi::{Concept, Length, Pages};
+
+fn concepts(criterion: &mut Criterion) {
+	let length = Length::L3;
+	let concepts: Vec<Concept> = Pages::new(length).flat_map(|page| return page.into_iter().flatten()).collect();
+	let mut group = criterion.benchmark_group("concepts");
+	group.throughput(Throughput::Elements(concepts.len() as u64));
+
+	group.bench_function("iterate", |bencher| {
+		bencher.iter(|| {
+			for concept in Pages::new(length).flat_map(|page| return page.into_iter().flatten()) {
+				black_box(concept);
+			}
+		})
+	});
+	group.bench_function("from_index", |bencher| {
+		bencher.iter(|| {
+			for index in 0..concepts.len() {
+				black_box(Concept::from_index(index, length).expect("failed to create concept"));
+			}
+		})
+	});
+	group.bench_function("index", |bencher| {
+		bencher.iter(|| {
+			for concept in &concepts {
+				black_box(concept.index());
+			}
+		})
+	});
+
+	group.finish();
+}
+
+/// Number of concepts to rank and unrank.
+const NUM_OF_CONCEPTS: usize = 100_000;
+
+fn ranking(criterion: &mut Criterion) {
+	let length = Length::L5;
+	let step = length.num_of_concepts() / NUM_OF_CONCEPTS;
+	let indexes: Vec<usize> = (0..NUM_OF_CONCEPTS).map(|position| return position * step).collect();
+	let concepts: Vec<Concept> = indexes
+		.iter()
+		.map(|index| return Concept::from_index(*index, length).expect("failed to create concept"))
+		.collect();
+	let mut group = criterion.benchmark_group("ranking");
+	group.throughput(Throughput::Elements(NUM_OF_CONCEPTS as u64));
+
+	group.bench_function("unrank", |bencher| {
+		bencher.iter(|| {
+			for index in &indexes {
+				black_box(Concept::from_index(black_box(*index), length).expect("failed to create concept"));
+			}
+		})
+	});
+	group.bench_function("rank", |bencher| {
+		bencher.iter(|| {
+			for concept in &concepts {
+				black_box(black_box(concept).index());
+			}
+		})
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, concepts, ranking);
+criterion_main!(benches);
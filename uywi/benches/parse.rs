@@ -0,0 +1,116 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::result_expect_used
+)]
+
+//! Benchmarks for reading UYWI Chiffre words.
+//!
+//! `render` is how words used to be parsed: build every word of every stem template that fits and compare strings.
+//! `structural` is [`Script::parse_all`], which matches the letters against the stem templates directly.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use std::convert::TryFrom;
+use unicode_segmentation::UnicodeSegmentation;
+use uywi::{Concept, ConceptOrWord, Length, Letter, Script, Word};
+
+/// Number of words in the text.
+const NUM_OF_WORDS: usize = 10_000;
+
+/// Build a text of words of different lengths, spread over all words of each length.
+fn words() -> Vec<String> {
+	let lengths = [Length::L2, Length::L3, Length::L4];
+
+	return (0..NUM_OF_WORDS)
+		.map(|position| {
+			let length = lengths[position % lengths.len()];
+			let index = position.wrapping_mul(7919) % length.num_of_words();
+
+			return Word::from_index(index, length)
+				.expect("failed to create word")
+				.to_string(Script::UywiChiffre);
+		})
+		.collect();
+}
+
+/// Parse `string` by building every word that could match and comparing strings.
+fn render(string: &str) -> Vec<ConceptOrWord> {
+	let script = Script::UywiChiffre;
+	let mut all = Vec::new();
+
+	if let Ok(concept) = script.from_concept(string) {
+		all.push(ConceptOrWord::Concept(concept));
+	}
+
+	for length in &Length::all() {
+		let probe = Concept::from_index(0, *length).expect("failed to create concept");
+
+		for stem_index in 0..length.stems_per_concept() {
+			let stem_index = u8::try_from(stem_index).expect("too many stems");
+			let structure = Word::from_concept(probe, stem_index, 0).expect("failed to create word").structure();
+
+			if structure.len() != string.graphemes(true).count() {
+				continue;
+			}
+
+			let radicals: String = structure
+				.iter()
+				.zip(string.graphemes(true))
+				.filter(|(letter, _)| return matches!(letter, Letter::Consonant(..)))
+				.map(|(_, grapheme)| return grapheme)
+				.collect();
+
+			if let Ok(concept) = script.from_concept(&radicals) {
+				for word in concept.into_iter().flatten() {
+					if word.stem_index() == stem_index && word.to_string(script) == string {
+						all.push(ConceptOrWord::Word(word));
+					}
+				}
+			}
+		}
+	}
+
+	return all;
+}
+
+fn parse(criterion: &mut Criterion) {
+	let words = words();
+	let mut group = criterion.benchmark_group("parse");
+	group.throughput(Throughput::Elements(words.len() as u64));
+
+	group.bench_function("structural", |bencher| {
+		bencher.iter(|| {
+			for word in &words {
+				black_box(Script::UywiChiffre.parse_all(word).expect("failed to parse word"));
+			}
+		})
+	});
+	group.bench_function("render", |bencher| {
+		bencher.iter(|| {
+			for word in &words {
+				black_box(render(word));
+			}
+		})
+	});
+
+	group.finish();
+}
+
+fn transliterate(criterion: &mut Criterion) {
+	let text = words().join(" ");
+	let mut group = criterion.benchmark_group("transliterate");
+	group.throughput(Throughput::Bytes(text.len() as u64));
+
+	group.bench_function("ipa", |bencher| {
+		bencher.iter(|| return uywi::transliterate(black_box(&text), Script::UywiChiffre, Script::IpaPeter))
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, parse, transliterate);
+criterion_main!(benches);
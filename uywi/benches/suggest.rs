@@ -0,0 +1,31 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(clippy::needless_return, clippy::missing_inline_in_public_items, clippy::non_ascii_literal)]
+
+//! Benchmarks for [`Script::suggest`].
+//!
+//! Every limit is unreachable, so strings two edits away are always searched.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use uywi::Script;
+
+fn suggest(criterion: &mut Criterion) {
+	let mut group = criterion.benchmark_group("suggest");
+
+	// a replaced letter and two added letters
+	group.bench_function("uywi_chiffre", |bencher| {
+		bencher.iter(|| {
+			for string in &["Bocxh", "oBocoho"] {
+				black_box(Script::UywiChiffre.suggest(string, usize::MAX));
+			}
+		})
+	});
+	// `t͡s` replaced by `d͡ʒ`
+	group.bench_function("ipa", |bencher| {
+		bencher.iter(|| return black_box(Script::IpaPeter.suggest("d͡ʒakʰ", usize::MAX)))
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, suggest);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+//! Alphabetical order of [`Concept`]s and [`Word`]s.
+
+use crate::*;
+use std::cmp::Ordering;
+
+/// Sorts [`Concept`]s and [`Word`]s alphabetically by their string in a [`Script`], following the order of [`Orthography::alphabet`].
+/// Characters that aren't part of the alphabet come after it, ordered by code point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Collation {
+	/// [`Script`] to sort by.
+	script: Script,
+}
+
+impl Collation {
+	/// Build [`Collation`] for `script`.
+	#[must_use]
+	pub const fn new(script: Script) -> Self {
+		return Self { script };
+	}
+
+	/// Get [`Script`].
+	#[must_use]
+	pub const fn script(self) -> Script {
+		return self.script;
+	}
+
+	/// Compare two strings of the [`Script`] alphabetically.
+	#[must_use]
+	pub fn compare(self, left: &str, right: &str) -> Ordering {
+		return self.key(left).cmp(&self.key(right));
+	}
+
+	/// Sort [`Concept`]s alphabetically, concepts with the same string keep their order by index.
+	pub fn sort_concepts(self, concepts: &mut [Concept]) {
+		concepts.sort_by_cached_key(|concept| return (self.key(&concept.to_string(self.script)), *concept));
+	}
+
+	/// Sort [`Word`]s alphabetically, words with the same string keep their order by index.
+	pub fn sort_words(self, words: &mut [Word]) {
+		words.sort_by_cached_key(|word| return (self.key(&word.to_string(self.script)), *word));
+	}
+
+	/// Build key to sort by: the position of every letter in the alphabet, the longest letter wins.
+	fn key(self, string: &str) -> Vec<usize> {
+		let alphabet = self.script.orthography().map_or(&[][..], |orthography| return orthography.alphabet());
+		let mut key = Vec::with_capacity(string.len());
+		let mut string_left = string;
+
+		while let Some(char) = string_left.chars().next() {
+			if let Some((position, letter)) = alphabet
+				.iter()
+				.enumerate()
+				.filter(|(_, letter)| return string_left.starts_with(*letter))
+				.max_by_key(|(_, letter)| return letter.len())
+			{
+				key.push(position);
+				string_left = &string_left[letter.len()..];
+			} else {
+				key.push(alphabet.len().padd(u32::from(char).pinto()));
+				string_left = &string_left[char.len_utf8()..];
+			}
+		}
+
+		return key;
+	}
+}
@@ -0,0 +1,152 @@
+//! Homophone and collision analysis.
+
+use crate::*;
+use std::{
+	collections::HashMap,
+	fmt::{self, Display, Formatter},
+};
+
+/// Number of [`Collision`]s shown by [`Collisions`]' [`Display`].
+const EXAMPLES: usize = 10;
+/// Number of [`Word`]s shown per [`Collision`] by [`Collisions`]' [`Display`].
+const EXAMPLE_WORDS: usize = 5;
+
+/// [`Word`]s that render to the same string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Collision {
+	/// Rendered string.
+	string: String,
+	/// [`Word`]s rendering to `string`.
+	words: Vec<Word>,
+}
+
+impl Collision {
+	/// Get rendered string.
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		return &self.string;
+	}
+
+	/// Get [`Word`]s rendering to the same string, in lexicon order.
+	#[must_use]
+	pub fn words(&self) -> &[Word] {
+		return &self.words;
+	}
+}
+
+/// Collision analysis of [`Word`]s in a [`Script`].
+/// Printing it with [`Display`] gives a report with counts and examples.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Collisions {
+	/// Analysed script.
+	script: Script,
+	/// Number of analysed words.
+	num_of_words: usize,
+	/// Number of distinct strings.
+	num_of_strings: usize,
+	/// Collisions, biggest first.
+	collisions: Vec<Collision>,
+}
+
+impl Collisions {
+	/// Analyse all [`Word`]s of a [`Length`].
+	/// Every rendered string is kept in memory until the analysis is done, which gets big for [`Length::L4`] and above.
+	#[must_use]
+	pub fn new(script: Script, length: Length) -> Self {
+		return Self::from_words(
+			script,
+			Pages::new(length).flat_map(|page| return page.into_iter().flatten().flatten().flatten()),
+		);
+	}
+
+	/// Analyse [`Word`]s.
+	#[must_use]
+	pub fn from_words(script: Script, words: impl IntoIterator<Item = Word>) -> Self {
+		let mut strings: HashMap<String, Vec<Word>> = HashMap::new();
+		let mut num_of_words = 0;
+
+		for word in words {
+			strings.entry(word.to_string(script)).or_default().push(word);
+			num_of_words = num_of_words.padd(1);
+		}
+
+		let num_of_strings = strings.len();
+		let mut collisions: Vec<_> = strings
+			.into_iter()
+			.filter(|(_, words)| return words.len() > 1)
+			.map(|(string, words)| return Collision { string, words })
+			.collect();
+
+		// biggest first, the string keeps the order stable
+		collisions.sort_by(|collision_1, collision_2| {
+			return collision_2
+				.words
+				.len()
+				.cmp(&collision_1.words.len())
+				.then_with(|| return collision_1.string.cmp(&collision_2.string));
+		});
+
+		return Self {
+			script,
+			num_of_words,
+			num_of_strings,
+			collisions,
+		};
+	}
+
+	/// Get analysed [`Script`].
+	#[must_use]
+	pub const fn script(&self) -> Script {
+		return self.script;
+	}
+
+	/// Number of analysed [`Word`]s.
+	#[must_use]
+	pub const fn num_of_words(&self) -> usize {
+		return self.num_of_words;
+	}
+
+	/// Number of distinct strings the [`Word`]s render to.
+	#[must_use]
+	pub const fn num_of_strings(&self) -> usize {
+		return self.num_of_strings;
+	}
+
+	/// Number of [`Word`]s that share their string with another [`Word`].
+	#[must_use]
+	pub fn num_of_colliding_words(&self) -> usize {
+		return self.collisions.iter().map(|collision| return collision.words.len()).sum();
+	}
+
+	/// Get [`Collision`]s, biggest first.
+	#[must_use]
+	pub fn collisions(&self) -> &[Collision] {
+		return &self.collisions;
+	}
+}
+
+impl Display for Collisions {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		writeln!(formatter, "Script: {}", self.script)?;
+		writeln!(formatter, "Words: {}", self.num_of_words)?;
+		writeln!(formatter, "Distinct strings: {}", self.num_of_strings)?;
+		writeln!(formatter, "Colliding strings: {}", self.collisions.len())?;
+		writeln!(formatter, "Colliding words: {}", self.num_of_colliding_words())?;
+
+		for collision in self.collisions.iter().take(EXAMPLES) {
+			write!(formatter, "{} ({} words):", collision.string, collision.words.len())?;
+
+			for word in collision.words.iter().take(EXAMPLE_WORDS) {
+				write!(formatter, " {}", word.index_as_string())?;
+			}
+
+			if collision.words.len() > EXAMPLE_WORDS {
+				write!(formatter, " ...")?;
+			}
+
+			writeln!(formatter)?;
+		}
+
+		return Ok(());
+	}
+}
@@ -0,0 +1,325 @@
+//! Completions for partially typed strings.
+
+use crate::*;
+use arrayvec::ArrayVec;
+use std::{collections::BTreeSet, iter};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A [`Concept`] or [`Word`] starting with the typed string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Completion {
+	/// Completed [`Concept`] or [`Word`].
+	concept_or_word: ConceptOrWord,
+	/// Graphemes left to type.
+	remaining: usize,
+}
+
+impl Completion {
+	/// Get [`ConceptOrWord`].
+	#[must_use]
+	pub const fn concept_or_word(self) -> ConceptOrWord {
+		return self.concept_or_word;
+	}
+
+	/// Get number of graphemes left to type, `0` if the typed string is already complete.
+	#[must_use]
+	pub const fn remaining(self) -> usize {
+		return self.remaining;
+	}
+}
+
+/// Return type from [`Script::complete`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Completions {
+	/// Graphemes that can follow the typed string.
+	next: Vec<String>,
+	/// Completions, ordered by graphemes left to type.
+	completions: Vec<Completion>,
+}
+
+impl Completions {
+	/// Get graphemes that can follow the typed string in any [`Concept`] or [`Word`], ordered by code point.
+	#[must_use]
+	pub fn next(&self) -> &[String] {
+		return &self.next;
+	}
+
+	/// Get [`Completion`]s, fewest graphemes left to type first.
+	#[must_use]
+	pub fn completions(&self) -> &[Completion] {
+		return &self.completions;
+	}
+}
+
+/// Complete `prefix` to [`Concept`]s and [`Word`]s of `length`, see [`Script::complete`].
+pub(crate) fn complete(script: Script, prefix: &str, length: Length, limit: usize) -> Completions {
+	let prefix = lenient::normalize(prefix);
+	let mut search = Search {
+		script,
+		prefix: &prefix,
+		prefix_len: prefix.graphemes(true).count(),
+		length,
+		limit,
+		next: BTreeSet::new(),
+		found: Vec::new(),
+		cutoff: None,
+	};
+	let independent = script.lettering().map_or(false, |lettering| return lettering.independent_letters());
+
+	// concepts are written radical by radical, words letter by letter, so only radicals that decide what is already written have to be known
+	if independent || matches!(script, Script::IpaPeter | Script::XSampa) {
+		let radicals = (0..length.as_int()).map(|index| return iter::once(index.pinto()).collect()).collect();
+		let spellings = search.spellings(radicals, None);
+		search.branch(&spellings, None, &mut [None; MAX_LENGTH]);
+
+		for stem_index in 0..length.stems_per_concept() {
+			let stem_index = stem_index.pinto();
+			let radicals = if independent {
+				structure::structures(length, stem_index)
+					.iter()
+					.map(|letter| {
+						return match letter {
+							Letter::Consonant(index) | Letter::DuplicateConsonant(index) => iter::once(*index).collect(),
+							Letter::Vowel(..) | Letter::DuplicateVowel(..) | Letter::Nasal(..) => ArrayVec::new(),
+						};
+					})
+					.collect()
+			}
+			// letters change with their neighbours, the rules tell which ones
+			else {
+				script::letter_radicals(length, stem_index)
+			};
+
+			for form_index in 0..length.num_of_forms() {
+				let word = Some((stem_index, form_index.pinto()));
+				let spellings = search.spellings(radicals.clone(), word);
+				search.branch(&spellings, word, &mut [None; MAX_LENGTH]);
+			}
+		}
+	}
+	// nothing can be known before the whole word is built
+	else {
+		for concept in Pages::new(length).flat_map(|page| return page.into_iter().flatten()) {
+			search.check(ConceptOrWord::Concept(concept), &concept.to_string(script));
+
+			for word in concept.into_iter().flatten() {
+				search.check(ConceptOrWord::Word(word), &word.to_string(script));
+			}
+		}
+	}
+
+	search.truncate();
+
+	return Completions {
+		next: search.next.into_iter().collect(),
+		completions: search.found,
+	};
+}
+
+/// What decides what is written for a letter of a [`Concept`] or [`Word`].
+struct Spelling {
+	/// Positions of the radicals in the concept the letter depends on.
+	radicals: ArrayVec<[u8; MAX_LENGTH]>,
+	/// Fewest graphemes the letter can be written with.
+	shortest: usize,
+}
+
+/// State of the search for completions.
+struct Search<'a> {
+	/// [`Script`] the prefix is written in.
+	script: Script,
+	/// Typed string, normalized to NFC.
+	prefix: &'a str,
+	/// Number of graphemes in the typed string.
+	prefix_len: usize,
+	/// [`Length`] to complete to.
+	length: Length,
+	/// Maximum number of completions.
+	limit: usize,
+	/// Graphemes found after the prefix.
+	next: BTreeSet<String>,
+	/// Completions found, in the order they were found.
+	found: Vec<Completion>,
+	/// Graphemes left to type of the last of the best completions, once there are enough of them.
+	cutoff: Option<usize>,
+}
+
+impl Search<'_> {
+	/// Assign radicals letter by letter in the order they are written, skipping every radical that doesn't fit the prefix or can only lead to
+	/// completions worse then the ones already found.
+	/// `spellings` belong to the stem template, or the radicals of the concept if `word` is [`None`], otherwise it holds the stem and form index.
+	fn branch(&mut self, spellings: &[Spelling], word: Option<(u8, u8)>, radicals: &mut [Option<Radical>; MAX_LENGTH]) {
+		let is_known = |spelling: &Spelling| return spelling.radicals.iter().all(|index| return radicals[usize::from(*index)].is_some());
+		// first letter that depends on a radical that isn't known yet
+		let open = spellings.iter().position(|spelling| return !is_known(spelling));
+
+		// fill the unknown radicals with anything, letters before the first open one don't depend on them
+		let (concept_or_word, texts) = if let Some(built) = self.build(&self.fill(radicals), word) {
+			built
+		} else {
+			return;
+		};
+		let known = texts[..open.unwrap_or(texts.len())].concat();
+
+		if !known.starts_with(self.prefix) && !self.prefix.starts_with(&known) {
+			return;
+		}
+
+		// the prefix is done, so the grapheme following it is known and only the best completions are left to find
+		if known.len() > self.prefix.len() {
+			self.insert_next(&known);
+
+			// letters are counted on their own, every one that isn't known yet is as short as it can be
+			let shortest = open.map_or(0, |open| {
+				return spellings[open..].iter().zip(&texts[open..]).fold(0, |shortest: usize, (spelling, text)| {
+					return shortest.padd(if is_known(spelling) {
+						text.graphemes(true).count()
+					} else {
+						spelling.shortest
+					});
+				});
+			});
+
+			if self.is_full(known.graphemes(true).count().padd(shortest).saturating_sub(self.prefix_len)) {
+				return;
+			}
+		}
+
+		if let Some(open) = open {
+			let index = spellings[open]
+				.radicals
+				.iter()
+				.map(|index| return usize::from(*index))
+				.find(|index| return radicals[*index].is_none())
+				.expect("open letter has no unknown radical");
+
+			for radical in self.candidates(index, radicals) {
+				radicals[index] = Some(radical);
+				self.branch(spellings, word, radicals);
+			}
+
+			radicals[index] = None;
+		} else {
+			self.check(concept_or_word, &known);
+		}
+	}
+
+	/// Build [`Spelling`]s from the radicals every letter depends on. Letters depending on one radical at most are built with every radical to
+	/// find how short they can be, others might not be written at all.
+	fn spellings(&self, letter_radicals: Vec<ArrayVec<[u8; MAX_LENGTH]>>, word: Option<(u8, u8)>) -> Vec<Spelling> {
+		let mut shortest: Vec<Option<usize>> = vec![None; letter_radicals.len()];
+
+		for index in 0..self.length.as_int() {
+			// only radicals that decide a letter on their own are needed
+			if !letter_radicals
+				.iter()
+				.any(|radicals| return radicals.len() <= 1 && radicals.first().map_or(true, |first| return usize::from(*first) == index))
+			{
+				continue;
+			}
+
+			for radical in Radical::all() {
+				let mut radicals = [None; MAX_LENGTH];
+				radicals[index] = Some(radical);
+
+				if let Some((_, texts)) = self.build(&self.fill(&radicals), word) {
+					for ((radicals, shortest), text) in letter_radicals.iter().zip(&mut shortest).zip(&texts) {
+						if radicals.len() <= 1 {
+							let len = text.graphemes(true).count();
+							*shortest = Some(shortest.map_or(len, |shortest| return shortest.min(len)));
+						}
+					}
+				}
+			}
+		}
+
+		return letter_radicals
+			.into_iter()
+			.zip(shortest)
+			.map(|(radicals, shortest)| {
+				return Spelling {
+					shortest: if radicals.len() <= 1 { shortest.unwrap_or(0) } else { 0 },
+					radicals,
+				};
+			})
+			.collect();
+	}
+
+	/// Get radicals that can be assigned to the radical at `index`, in the order they are iterated at that position.
+	fn candidates(&self, index: usize, radicals: &[Option<Radical>; MAX_LENGTH]) -> Vec<Radical> {
+		let used: ArrayVec<[_; MAX_LENGTH]> = radicals.iter().flatten().copied().collect();
+
+		return self
+			.length
+			.radicals_ordered(usize::from(self.length.radical_order()[index]), &used)
+			.collect();
+	}
+
+	/// Fill unknown radicals with unused ones.
+	fn fill(&self, radicals: &[Option<Radical>; MAX_LENGTH]) -> ArrayVec<[Radical; MAX_LENGTH]> {
+		let mut unused = Radical::all().filter(|radical| return !radicals.contains(&Some(*radical)));
+
+		return radicals[..self.length.as_int()]
+			.iter()
+			.map(|radical| return radical.or_else(|| return unused.next()).expect("not enough radicals"))
+			.collect();
+	}
+
+	/// Build [`Concept`] or [`Word`] from `radicals` and get what is written for each of its letters.
+	fn build(&self, radicals: &[Radical], word: Option<(u8, u8)>) -> Option<(ConceptOrWord, Vec<String>)> {
+		let concept = Concept::from_radicals(radicals).ok()?;
+
+		if let Some((stem_index, form_index)) = word {
+			let word = Word::from_concept(concept, stem_index, form_index).ok()?;
+
+			return Some((ConceptOrWord::Word(word), query::letters(self.script, word)?));
+		} else {
+			let texts = radicals
+				.iter()
+				.map(|radical| return query::radical_letter(self.script, *radical))
+				.collect();
+
+			return Some((ConceptOrWord::Concept(concept), texts));
+		}
+	}
+
+	/// Add `concept_or_word` if `string` starts with the prefix.
+	fn check(&mut self, concept_or_word: ConceptOrWord, string: &str) {
+		if !string.starts_with(self.prefix) {
+			return;
+		}
+
+		self.insert_next(string);
+		self.found.push(Completion {
+			concept_or_word,
+			remaining: string.graphemes(true).count().saturating_sub(self.prefix_len),
+		});
+
+		// don't keep more then needed around
+		if self.found.len() >= self.limit.saturating_mul(2).max(64) {
+			self.truncate();
+		}
+	}
+
+	/// Remember the grapheme following the prefix in `string`.
+	fn insert_next(&mut self, string: &str) {
+		if let Some(grapheme) = string[self.prefix.len()..].graphemes(true).next() {
+			self.next.insert(grapheme.to_owned());
+		}
+	}
+
+	/// Keep only the best completions, completions with the same number of graphemes left keep the order they were found in.
+	fn truncate(&mut self) {
+		self.found.sort_by_key(|completion| return completion.remaining);
+		self.found.truncate(self.limit);
+
+		if self.found.len() == self.limit {
+			self.cutoff = self.found.last().map(|completion| return completion.remaining);
+		}
+	}
+
+	/// Check if completions with `remaining` graphemes left to type can't be among the best ones anymore, the ones found first win ties.
+	fn is_full(&self, remaining: usize) -> bool {
+		return self.limit == 0 || self.cutoff.map_or(false, |cutoff| return remaining >= cutoff);
+	}
+}
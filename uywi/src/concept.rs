@@ -3,66 +3,52 @@
 use crate::*;
 use array_init::array_init;
 use arrayvec::ArrayVec;
-use std::mem;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
 
 /// A concept.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Concepts are ordered by [`Length`] first and [`Concept::index`] second, use [`Collation`] for alphabetical order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+	feature = "serde",
+	derive(Deserialize, Serialize),
+	serde(into = "(Length, usize)", try_from = "(Length, usize)")
+)]
 pub struct Concept {
 	/// Concepts are stored as [`Radical`]s.
-	radicals: [Radical; 4],
+	radicals: [Radical; MAX_LENGTH],
 	/// Concept length.
 	length: Length,
 }
 
 impl Concept {
 	/// Build [`Concept`] from members. Used for internal purposes.
-	pub(crate) const fn new(radicals: [Radical; 4], length: Length) -> Self {
+	pub(crate) const fn new(radicals: [Radical; MAX_LENGTH], length: Length) -> Self {
 		return Self { radicals, length };
 	}
 
-	/// Build [`Concept`] from concept index.
+	/// Build [`Concept`] from [`Radical`]s in the order they appear in the concept.
 	/// # Errors
-	/// [`Error::ConceptIndexInvalid`] if `index` is higher then the number of pages of the given [`Length`]
-	pub fn from_index(index: usize, length: Length) -> Result<Self> {
-		// check for valid concept index
-		if index >= length.num_of_concepts() {
-			return Err(Error::ConceptIndexInvalid);
-		}
-
-		// storing the radical indexes
-		let mut radicals = ArrayVec::<[_; 4]>::new();
-		// the concept index we start at
-		let mut index_left = index;
-
-		// get interval at which each position's radical changes
-		for (position, interval) in length.radical_intervals().into_iter().enumerate() {
-			// how many intervals fit into the left concept index
-			let intervals = index_left.pdiv(interval);
-
-			// get radical indexes with the appropriate ordering
-			let mut radicals_ordered = length.radicals_ordered(position, &radicals);
-
-			// get the radical
-			let radical = radicals_ordered.nth(intervals).expect("no radical found at given interval");
-
-			// drop the iterator because we need a `&mut` to `radicals`
-			mem::drop(radicals_ordered);
-
-			// store it into the concept
-			radicals.push(radical);
-
-			// store the concept index thats left
-			index_left = index_left.psub(intervals.pmul(interval));
+	/// [`Error::LengthInvalid`] if the number of `radicals` has no corresponding [`Length`]
+	///
+	/// [`Error::ConceptRadicalDuplicate`] if `radicals` contains the same [`Radical`] twice
+	pub fn from_radicals(radicals: &[Radical]) -> Result<Self> {
+		let length = Length::new(radicals.len())?;
+
+		// every radical can only be used once in a concept
+		for (position, radical) in radicals.iter().enumerate() {
+			if radicals[..position].contains(radical) {
+				return Err(Error::ConceptRadicalDuplicate);
+			}
 		}
 
-		// cache order
-		let radical_order = length.radical_order();
-
-		// reorder them
 		let radicals = array_init(|index| {
 			// check if there is a radical
-			return if let Some(radical) = radical_order.get(index) {
-				radicals[usize::from(*radical)]
+			return if let Some(radical) = radicals.get(index) {
+				*radical
 			}
 			// fill it with `0`s if not
 			else {
@@ -70,7 +56,21 @@ impl Concept {
 			};
 		});
 
-		return Ok(Self { radicals, length });
+		return Ok(Self::new(radicals, length));
+	}
+
+	/// Build [`Concept`] from concept index.
+	/// # Errors
+	/// [`Error::ConceptIndexInvalid`] if `index` is higher then the number of pages of the given [`Length`]
+	pub fn from_index(index: usize, length: Length) -> Result<Self> {
+		let ranking = Config::current().ranking(length);
+
+		// check for valid concept index
+		if index >= ranking.concepts() {
+			return Err(Error::ConceptIndexInvalid);
+		}
+
+		return Ok(Self::new(ranking.unrank(index), length));
 	}
 
 	/// Build [`Concept`] from index string.
@@ -92,7 +92,8 @@ impl Concept {
 	}
 
 	/// Get radicals.
-	pub(crate) fn radicals(self) -> ArrayVec<[Radical; 4]> {
+	#[must_use]
+	pub fn radicals(self) -> ArrayVec<[Radical; MAX_LENGTH]> {
 		let mut radicals = ArrayVec::new();
 
 		for index in 0..self.length.as_int() {
@@ -105,35 +106,7 @@ impl Concept {
 	/// Get index.
 	#[must_use]
 	pub fn index(self) -> usize {
-		// store calculated concept index
-		let mut concept_index = 0;
-		// store already used radicals
-		let mut radicals_used = ArrayVec::<[_; 4]>::new();
-		// cache radical intervals
-		let radical_intervals = self.length.radical_intervals();
-
-		for (position, order) in self.length.radical_order_mirrored().iter().enumerate() {
-			// get radical from correct position
-			let radical = self.radicals()[usize::from(*order)];
-			// get radicals in appropriate order for this position and filter already used radicals
-			let mut radicals = self.length.radicals_ordered(position, &radicals_used);
-
-			// find the ordered radical index
-			let ordered_radical_index = radicals
-				.position(|ordered_radical| return ordered_radical == radical)
-				.expect("radical not found");
-
-			// add index multiplied with interval size to the concept index
-			concept_index = concept_index.padd(ordered_radical_index.pmul(radical_intervals[position]));
-
-			// drop the iterator because we need a `&mut` to `radicals_used`
-			mem::drop(radicals);
-
-			// store found radical
-			radicals_used.push(radical);
-		}
-
-		return concept_index;
+		return Config::current().ranking(self.length).rank(&self.radicals[..self.length.as_int()]);
 	}
 
 	/// Get index as string.
@@ -162,6 +135,18 @@ impl Concept {
 	}
 }
 
+impl Ord for Concept {
+	fn cmp(&self, other: &Self) -> Ordering {
+		return self.length.cmp(&other.length).then_with(|| return self.index().cmp(&other.index()));
+	}
+}
+
+impl PartialOrd for Concept {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		return Some(self.cmp(other));
+	}
+}
+
 impl IntoIterator for Concept {
 	type Item = Stem;
 	type IntoIter = Stems;
@@ -174,22 +159,83 @@ impl IntoIterator for Concept {
 
 /// Convenience type to make it easier to deal with radicals.
 /// We don't really want to jumble around with [`str`]s and we don't want to accidentally do calculations.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// Radicals have two indexes: [`Radical::index`] is the position in the radical inventory of the current [`Config`] and is what concept indexes,
+/// pages and rows are based on, [`Radical::id`] is the position in the radicals of every [`Script`] and stays the same in every inventory.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Radical {
-	/// Index of the radical. Corresponds to [`ScriptExtPriv::radicals`].
+	/// Index of the radical in the radical inventory.
 	index: u8,
 }
 
 impl Radical {
+	/// Build [`Radical`] from index without checking it against the current [`Config`]. Used for internal purposes.
+	pub(crate) const fn new(index: u8) -> Self {
+		return Self { index };
+	}
+
 	/// Build new [`Radical`] from index.
+	/// # Panics
+	/// Panics if `index` is equal or higher then the number of radicals in the current [`Config`].
+	#[must_use]
 	pub fn from_index(index: u8) -> Self {
-		assert!(usize::from(index) < NUM_OF_RADICALS, "invalid radical");
+		assert!(usize::from(index) < Config::current().num_of_radicals(), "invalid radical");
 
 		return Self { index };
 	}
 
-	/// Get index of radical.
+	/// Build [`Radical`] from its position in the radicals of every [`Script`].
+	/// # Errors
+	/// [`Error::ConceptRadicalInvalid`] if the radical isn't part of the radical inventory of the current [`Config`]
+	pub fn from_id(id: u8) -> Result<Self> {
+		return Config::current()
+			.radicals()
+			.iter()
+			.position(|radical| return *radical == id)
+			.map(|index| return Self::new(index.pinto()))
+			.ok_or(Error::ConceptRadicalInvalid);
+	}
+
+	/// Iterator over all [`Radical`]s in the radical inventory of the current [`Config`].
+	pub fn all() -> impl Iterator<Item = Self> {
+		return (0..Config::current().num_of_radicals()).map(|index| return Self::new(index.pinto()));
+	}
+
+	/// Get index of radical in the radical inventory.
+	#[must_use]
 	pub const fn index(self) -> u8 {
 		return self.index;
 	}
+
+	/// Get position of radical in the radicals of every [`Script`].
+	#[must_use]
+	pub fn id(self) -> u8 {
+		return Config::current().radicals()[usize::from(self.index)];
+	}
+
+	/// Get phonological features, e.g. to find all voiceless radicals:
+	/// ```
+	/// # use uywi::Radical;
+	/// let voiceless: Vec<_> = Radical::all().filter(|radical| return radical.features().is_voiceless()).collect();
+	/// ```
+	#[must_use]
+	pub fn features(self) -> RadicalFeatures {
+		return script::radical_features(self.id());
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<Concept> for (Length, usize) {
+	fn from(concept: Concept) -> Self {
+		return (concept.length, concept.index());
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<(Length, usize)> for Concept {
+	type Error = Error;
+
+	fn try_from((length, index): (Length, usize)) -> Result<Self> {
+		return Self::from_index(index, length);
+	}
 }
@@ -0,0 +1,221 @@
+//! Language configuration.
+
+use crate::{length::Ranking, structure, *};
+use std::sync::OnceLock;
+
+/// Installed [`Config`], set once when it's first used or installed and never changed afterwards.
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Language configuration.
+/// Everything that builds or renders [`Concept`]s and [`Word`]s follows the installed configuration, see [`Config::install`].
+/// It can only be installed once, before anything uses it, so [`Concept`]s and [`Word`]s never outlive the configuration they were built with.
+#[derive(Clone, Debug)]
+pub struct Config {
+	/// Stem templates of each [`Length`], ordered like [`Length::all`].
+	templates: Vec<Vec<StemTemplate>>,
+	/// Forms of each [`Length`], ordered like [`Length::all`].
+	forms: Vec<Vec<Form>>,
+	/// Radical inventory, ordered like [`Radical::index`], storing [`Radical::id`]s.
+	radicals: Vec<u8>,
+	/// Tables to rank and unrank concepts of each [`Length`] with the radical inventory, ordered like [`Length::all`].
+	rankings: Vec<Ranking>,
+	/// Phonological rules of [`Script::IpaPeter`].
+	ipa_rules: Rules,
+	/// Stress rule.
+	stress: Stress,
+}
+
+impl Config {
+	/// Build [`Config`] with the built-in defaults.
+	#[must_use]
+	pub fn new() -> Self {
+		let radicals: Vec<u8> = (0..NUM_OF_RADICALS).map(PConvert::pinto).collect();
+
+		return Self {
+			templates: Length::all()
+				.iter()
+				.map(|length| {
+					return structure::list(*length)
+						.iter()
+						.map(|template| return template.parse().expect("built-in stem template is invalid"))
+						.collect();
+				})
+				.collect(),
+			forms: Length::all()
+				.iter()
+				.map(|length| {
+					return structure::forms(*length)
+						.iter()
+						.map(|form| return form.parse().expect("built-in form is invalid"))
+						.collect();
+				})
+				.collect(),
+			radicals: radicals.clone(),
+			rankings: Length::all().iter().map(|length| return Ranking::new(*length, &radicals)).collect(),
+			ipa_rules: Rules::default(),
+			stress: Stress::default(),
+		};
+	}
+
+	/// Get installed [`Config`], installs [`Config::new`] if nothing was installed yet.
+	#[must_use]
+	pub fn current() -> &'static Self {
+		return CONFIG.get_or_init(Self::new);
+	}
+
+	/// Install as the [`Config`] of the whole program.
+	/// Install it before building any [`Concept`]s or [`Word`]s, anything that uses the configuration installs the default one.
+	/// # Errors
+	/// [`Error::ConfigInstalled`] if a [`Config`] was already installed or used
+	pub fn install(self) -> Result<()> {
+		return CONFIG.set(self).map_err(|_| return Error::ConfigInstalled);
+	}
+
+	/// Get [`StemTemplate`]s of a [`Length`].
+	#[must_use]
+	pub fn templates(&self, length: Length) -> &[StemTemplate] {
+		return &self.templates[length.position()];
+	}
+
+	/// Set [`StemTemplate`]s of a [`Length`].
+	/// # Errors
+	/// [`Error::TemplatesEmpty`] if `templates` is empty
+	///
+	/// [`Error::TemplatesTooMany`] if `templates` has more then 255 [`StemTemplate`]s
+	///
+	/// See [`StemTemplate::validate`] for errors in a [`StemTemplate`].
+	pub fn set_templates(&mut self, length: Length, templates: Vec<StemTemplate>) -> Result<()> {
+		if templates.is_empty() {
+			return Err(Error::TemplatesEmpty);
+		}
+
+		// stem indexes are stored as `u8` and iteration has to be able to step past the last one
+		if templates.len() > usize::from(u8::MAX) {
+			return Err(Error::TemplatesTooMany);
+		}
+
+		for template in &templates {
+			template.validate(length)?;
+		}
+
+		self.templates[length.position()] = templates;
+
+		return Ok(());
+	}
+
+	/// Get [`Form`]s of a [`Length`].
+	#[must_use]
+	pub fn forms(&self, length: Length) -> &[Form] {
+		return &self.forms[length.position()];
+	}
+
+	/// Set [`Form`]s of a [`Length`].
+	/// # Errors
+	/// [`Error::FormsEmpty`] if `forms` is empty
+	///
+	/// [`Error::FormsTooMany`] if `forms` has more then 255 [`Form`]s
+	pub fn set_forms(&mut self, length: Length, forms: Vec<Form>) -> Result<()> {
+		if forms.is_empty() {
+			return Err(Error::FormsEmpty);
+		}
+
+		// form indexes are stored as `u8` and iteration has to be able to step past the last one
+		if forms.len() > usize::from(u8::MAX) {
+			return Err(Error::FormsTooMany);
+		}
+
+		self.forms[length.position()] = forms;
+
+		return Ok(());
+	}
+
+	/// Get radical inventory, ordered like [`Radical::index`], storing [`Radical::id`]s.
+	#[must_use]
+	pub fn radicals(&self) -> &[u8] {
+		return &self.radicals;
+	}
+
+	/// Number of radicals in the radical inventory.
+	#[must_use]
+	pub fn num_of_radicals(&self) -> usize {
+		return self.radicals.len();
+	}
+
+	/// Get tables to rank and unrank concepts of `length`.
+	pub(crate) fn ranking(&self, length: Length) -> &Ranking {
+		return &self.rankings[length.position()];
+	}
+
+	/// Set radical inventory, by default it has all [`NUM_OF_RADICALS`] radicals of the built-in [`Script`]s.
+	///
+	/// `radicals` are [`Radical::id`]s, their position in `radicals` becomes their [`Radical::index`].
+	/// Concept indexes, pages and rows are calculated from [`Radical::index`], so they change with the inventory:
+	/// with the subset `[0, 1, 3]` the radical `h` keeps its [`Radical::id`] `3`, but has the [`Radical::index`] `2`.
+	/// The last radical of a concept starts at `h` when iterated, wherever it is in the inventory, or like all other radicals at the index of its
+	/// position if the inventory doesn't have `h`.
+	/// The inventory can only hold radicals the built-in [`Script`]s know, it can't be extended past [`NUM_OF_RADICALS`].
+	/// # Errors
+	/// [`Error::RadicalsTooFew`] if there are less radicals then [`MAX_LENGTH`]
+	///
+	/// [`Error::RadicalsTooMany`] if there are more radicals then [`NUM_OF_RADICALS`]
+	///
+	/// [`Error::RadicalsInvalid`] if a [`Radical::id`] is equal or higher then [`NUM_OF_RADICALS`]
+	///
+	/// [`Error::RadicalsDuplicate`] if a radical is used twice
+	pub fn set_radicals(&mut self, radicals: Vec<u8>) -> Result<()> {
+		// every concept needs enough radicals to never use one twice
+		if radicals.len() < MAX_LENGTH {
+			return Err(Error::RadicalsTooFew);
+		}
+
+		// radical indexes are stored as `u8`, which the built-in radicals always fit into
+		if radicals.len() > NUM_OF_RADICALS {
+			return Err(Error::RadicalsTooMany);
+		}
+
+		for (position, radical) in radicals.iter().enumerate() {
+			// the built-in scripts look up every radical by its id
+			if usize::from(*radical) >= NUM_OF_RADICALS {
+				return Err(Error::RadicalsInvalid);
+			}
+
+			if radicals[..position].contains(radical) {
+				return Err(Error::RadicalsDuplicate);
+			}
+		}
+
+		self.rankings = Length::all().iter().map(|length| return Ranking::new(*length, &radicals)).collect();
+		self.radicals = radicals;
+
+		return Ok(());
+	}
+
+	/// Get phonological [`Rules`] of [`Script::IpaPeter`].
+	#[must_use]
+	pub const fn ipa_rules(&self) -> &Rules {
+		return &self.ipa_rules;
+	}
+
+	/// Set phonological [`Rules`] of [`Script::IpaPeter`], by default they are [`Rules::default`].
+	/// Words are read back by following the installed rules, so letters can be removed or moved wherever they allow it.
+	pub fn set_ipa_rules(&mut self, rules: Rules) {
+		self.ipa_rules = rules;
+	}
+
+	/// Get [`Stress`] rule.
+	#[must_use]
+	pub const fn stress(&self) -> Stress {
+		return self.stress;
+	}
+
+	/// Set [`Stress`] rule used by [`Word::syllables`], by default it's [`Stress::Penultimate`].
+	pub fn set_stress(&mut self, stress: Stress) {
+		self.stress = stress;
+	}
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		return Self::new();
+	}
+}
@@ -0,0 +1,150 @@
+//! Step-by-step derivation of how a [`Script`] renders a [`Word`].
+
+use crate::*;
+
+/// Why a segment was changed, see [`Step::assimilation`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Assimilation {
+	/// Vowels around dark consonants turn dark.
+	Dark,
+	/// Vowels around light consonants turn light.
+	Light,
+	/// Vowels after shading consonants turn shading.
+	Shading,
+	/// Consonants around voiceless consonants turn to their voiceless alternative.
+	Alternative,
+	/// Consonants at the beginning or end of a word are removed, together with what follows the last one.
+	Removal,
+	/// Vowels in nasal position turn nasal.
+	Nasal,
+	/// Vowels after rounding consonants turn rounding.
+	Rounding,
+}
+
+/// Segment changed by a [`Step`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+	/// Position in the segment list.
+	position: usize,
+	/// Segment before the change.
+	before: String,
+	/// Segment after the change.
+	after: String,
+}
+
+impl Change {
+	/// Build [`Change`].
+	pub(crate) const fn new(position: usize, before: String, after: String) -> Self {
+		return Self { position, before, after };
+	}
+
+	/// Get position in the segment list.
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		return self.position;
+	}
+
+	/// Get segment before the change.
+	#[must_use]
+	pub fn before(&self) -> &str {
+		return &self.before;
+	}
+
+	/// Get segment after the change.
+	/// Some changes only affect later assimilations and don't change how the segment is written, e.g. a neutral `a` turning dark.
+	#[must_use]
+	pub fn after(&self) -> &str {
+		return &self.after;
+	}
+}
+
+/// Segments after applying one rule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+	/// Name of the applied rule.
+	rule: String,
+	/// Assimilation the rule is part of.
+	assimilation: Assimilation,
+	/// Segments after the rule.
+	segments: Vec<String>,
+	/// Changed segments.
+	changes: Vec<Change>,
+}
+
+impl Step {
+	/// Build [`Step`].
+	pub(crate) fn new(rule: &str, assimilation: Assimilation, segments: Vec<String>, changes: Vec<Change>) -> Self {
+		return Self {
+			rule: rule.to_owned(),
+			assimilation,
+			segments,
+			changes,
+		};
+	}
+
+	/// Get name of the applied rule, see [`Rule::name`](crate::Rule::name).
+	#[must_use]
+	pub fn rule(&self) -> &str {
+		return &self.rule;
+	}
+
+	/// Get [`Assimilation`] the rule is part of.
+	#[must_use]
+	pub const fn assimilation(&self) -> Assimilation {
+		return self.assimilation;
+	}
+
+	/// Get segments after the rule, removed segments are empty.
+	#[must_use]
+	pub fn segments(&self) -> &[String] {
+		return &self.segments;
+	}
+
+	/// Get [`Change`]s, empty if the rule didn't apply.
+	#[must_use]
+	pub fn changes(&self) -> &[Change] {
+		return &self.changes;
+	}
+}
+
+/// Return type from [`Word::derivation`](crate::Word::derivation).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Derivation {
+	/// Segments before any rule.
+	base: Vec<String>,
+	/// Every rule in the order they are applied.
+	steps: Vec<Step>,
+	/// Features of every final segment that is a vowel.
+	vowel_features: Vec<Option<VowelFeatures>>,
+}
+
+impl Derivation {
+	/// Build [`Derivation`].
+	pub(crate) const fn new(base: Vec<String>, steps: Vec<Step>, vowel_features: Vec<Option<VowelFeatures>>) -> Self {
+		return Self { base, steps, vowel_features };
+	}
+
+	/// Get segments before any rule, one per letter of the stem template.
+	#[must_use]
+	pub fn base(&self) -> &[String] {
+		return &self.base;
+	}
+
+	/// Get [`Step`]s in the order they are applied.
+	#[must_use]
+	pub fn steps(&self) -> &[Step] {
+		return &self.steps;
+	}
+
+	/// Get final segments, joined they are the rendered [`Word`](crate::Word).
+	#[must_use]
+	pub fn segments(&self) -> &[String] {
+		return self.steps.last().map_or(&self.base, |step| return &step.segments);
+	}
+
+	/// Get [`VowelFeatures`] of every final segment after all assimilations, [`None`] if the segment isn't a vowel.
+	#[must_use]
+	pub fn vowel_features(&self) -> &[Option<VowelFeatures>] {
+		return &self.vowel_features;
+	}
+}
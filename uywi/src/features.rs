@@ -0,0 +1,245 @@
+//! Phonological features of radicals and vowels.
+
+/// Place of articulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Place {
+	/// Both lips, e.g. `p`.
+	Bilabial,
+	/// Lower lip and upper teeth, e.g. `f`.
+	Labiodental,
+	/// Both lips and soft palate, e.g. `w`.
+	LabialVelar,
+	/// Both lips and hard palate, e.g. `ɥ`.
+	LabialPalatal,
+	/// Teeth, e.g. `θ`.
+	Dental,
+	/// Alveolar ridge, e.g. `s`.
+	Alveolar,
+	/// Behind the alveolar ridge, e.g. `ʃ`.
+	Postalveolar,
+	/// Behind the alveolar ridge with the tongue raised to the hard palate, e.g. `ɕ`.
+	AlveoloPalatal,
+	/// Tongue curled back, e.g. `ʂ`.
+	Retroflex,
+	/// Hard palate, e.g. `j`.
+	Palatal,
+	/// Soft palate, e.g. `k`.
+	Velar,
+	/// Uvula, e.g. `ʁ`.
+	Uvular,
+	/// Pharynx, e.g. `ħ`.
+	Pharyngeal,
+	/// Glottis, e.g. `h`.
+	Glottal,
+}
+
+/// Manner of articulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Manner {
+	/// Air is stopped completely, e.g. `t`.
+	Plosive,
+	/// Air is stopped in the mouth and flows through the nose, e.g. `m`.
+	Nasal,
+	/// Vibrating articulator, e.g. `r`.
+	Trill,
+	/// Turbulent air, e.g. `s`.
+	Fricative,
+	/// Plosive released as a fricative, e.g. `t͡s`.
+	Affricate,
+	/// Articulators approach each other without turbulence, e.g. `j`.
+	Approximant,
+	/// Approximant with air flowing along the sides of the tongue, e.g. `l`.
+	LateralApproximant,
+}
+
+/// Voicing of a radical.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Voicing {
+	/// Vocal folds vibrate.
+	Voiced,
+	/// Vocal folds don't vibrate.
+	Voiceless,
+}
+
+/// Secondary articulation of a radical, it colors the vowels around it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Articulation {
+	/// No secondary articulation.
+	Neutral,
+	/// Velarized or pharyngealized, marked with `ˤ`.
+	Dark,
+	/// Palatalized, marked with `ʲ`.
+	Light,
+}
+
+/// How a radical colors the vowel after it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Coloring {
+	/// Light vowels after it turn central or open, e.g. `e` to `æ`.
+	Shading,
+	/// Vowels after it turn rounded, e.g. `e` to `ø`.
+	Rounding,
+}
+
+/// Phonological features of a radical, return type from [`Radical::features`](crate::Radical::features).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RadicalFeatures {
+	/// Place of articulation.
+	place: Place,
+	/// Manner of articulation.
+	manner: Manner,
+	/// Voicing.
+	voicing: Option<Voicing>,
+	/// Secondary articulation.
+	articulation: Articulation,
+	/// Coloring of the following vowel.
+	coloring: Option<Coloring>,
+}
+
+impl RadicalFeatures {
+	/// Build [`RadicalFeatures`].
+	pub(crate) const fn new(place: Place, manner: Manner, voicing: Option<Voicing>, articulation: Articulation, coloring: Option<Coloring>) -> Self {
+		return Self {
+			place,
+			manner,
+			voicing,
+			articulation,
+			coloring,
+		};
+	}
+
+	/// Get place of articulation.
+	#[must_use]
+	pub const fn place(self) -> Place {
+		return self.place;
+	}
+
+	/// Get manner of articulation.
+	#[must_use]
+	pub const fn manner(self) -> Manner {
+		return self.manner;
+	}
+
+	/// Get voicing, [`None`] for the glottal stop `ʔ`, which doesn't take part in voicing assimilation.
+	#[must_use]
+	pub const fn voicing(self) -> Option<Voicing> {
+		return self.voicing;
+	}
+
+	/// Get secondary articulation.
+	#[must_use]
+	pub const fn articulation(self) -> Articulation {
+		return self.articulation;
+	}
+
+	/// Get coloring of the following vowel.
+	#[must_use]
+	pub const fn coloring(self) -> Option<Coloring> {
+		return self.coloring;
+	}
+
+	/// Get if the radical is voiceless.
+	#[must_use]
+	pub fn is_voiceless(self) -> bool {
+		return self.voicing == Some(Voicing::Voiceless);
+	}
+
+	/// Get if the radical is dark.
+	#[must_use]
+	pub fn is_dark(self) -> bool {
+		return self.articulation == Articulation::Dark;
+	}
+
+	/// Get if the radical is light.
+	#[must_use]
+	pub fn is_light(self) -> bool {
+		return self.articulation == Articulation::Light;
+	}
+
+	/// Get if the radical shades the following vowel.
+	#[must_use]
+	pub fn is_shading(self) -> bool {
+		return self.coloring == Some(Coloring::Shading);
+	}
+
+	/// Get if the radical rounds the following vowel.
+	#[must_use]
+	pub fn is_rounding(self) -> bool {
+		return self.coloring == Some(Coloring::Rounding);
+	}
+}
+
+/// Vowel height.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Height {
+	/// E.g. `i`.
+	Close,
+	/// E.g. `e`.
+	CloseMid,
+	/// E.g. `ɔ`.
+	OpenMid,
+	/// E.g. `æ`.
+	NearOpen,
+	/// E.g. `a`.
+	Open,
+}
+
+/// Vowel backness.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backness {
+	/// E.g. `i`.
+	Front,
+	/// E.g. `ɨ`.
+	Central,
+	/// E.g. `u`.
+	Back,
+}
+
+/// Phonological features of a vowel, return type from [`FormVowel::features`](crate::FormVowel::features).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VowelFeatures {
+	/// Height.
+	height: Height,
+	/// Backness.
+	backness: Backness,
+	/// Rounded.
+	rounded: bool,
+	/// Nasal.
+	nasal: bool,
+}
+
+impl VowelFeatures {
+	/// Build [`VowelFeatures`].
+	pub(crate) const fn new(height: Height, backness: Backness, rounded: bool, nasal: bool) -> Self {
+		return Self {
+			height,
+			backness,
+			rounded,
+			nasal,
+		};
+	}
+
+	/// Get height.
+	#[must_use]
+	pub const fn height(self) -> Height {
+		return self.height;
+	}
+
+	/// Get backness.
+	#[must_use]
+	pub const fn backness(self) -> Backness {
+		return self.backness;
+	}
+
+	/// Get if the vowel is rounded.
+	#[must_use]
+	pub const fn is_rounded(self) -> bool {
+		return self.rounded;
+	}
+
+	/// Get if the vowel is nasal.
+	#[must_use]
+	pub const fn is_nasal(self) -> bool {
+		return self.nasal;
+	}
+}
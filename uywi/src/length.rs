@@ -1,30 +1,52 @@
 //! Length configuration.
 
-use crate::{structure, *};
+use crate::*;
 use arrayvec::ArrayVec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 
+/// Maximum number of radicals in a concept.
+pub const MAX_LENGTH: usize = 5;
+/// [`Radical::id`] of `h`, where the last radical of a concept starts when iterated, see [`Config::set_radicals`].
+const START_ID: u8 = 3;
+
 /// Concept length.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(into = "usize", try_from = "usize"))]
 pub enum Length {
+	/// One radical, used for particles.
+	L1,
 	/// Two radicals.
 	L2,
 	/// Three radicals.
 	L3,
 	/// Four radicals.
 	L4,
+	/// Five radicals.
+	L5,
 }
 
 #[allow(clippy::unused_self)]
 impl Length {
+	/// All [`Length`]s.
+	#[must_use]
+	pub const fn all() -> [Self; 5] {
+		return [Self::L1, Self::L2, Self::L3, Self::L4, Self::L5];
+	}
+
 	/// Build new [`Length`].
 	/// # Errors
 	/// [`Error::LengthInvalid`] if `length` has no corresponding [`Length`]
 	pub fn new(length: usize) -> Result<Self> {
 		match length {
+			1 => return Ok(Self::L1),
 			2 => return Ok(Self::L2),
 			3 => return Ok(Self::L3),
 			4 => return Ok(Self::L4),
+			5 => return Ok(Self::L5),
 			_ => return Err(Error::LengthInvalid),
 		}
 	}
@@ -33,21 +55,22 @@ impl Length {
 	#[must_use]
 	pub fn as_int(self) -> usize {
 		return match self {
+			Self::L1 => 1,
 			Self::L2 => 2,
 			Self::L3 => 3,
 			Self::L4 => 4,
+			Self::L5 => 5,
 		};
 	}
 
 	/// Total number of pages.
 	#[must_use]
 	pub fn num_of_pages(self) -> usize {
-		return match self {
-			Self::L2 => 1,
-			Self::L3 => NUM_OF_RADICALS,
-			// `Radicals * (Radicals - 1)`
-			Self::L4 => NUM_OF_RADICALS.pmul(NUM_OF_RADICALS.psub(1)),
-		};
+		// every radical position except the last two decides the page
+		// `Radicals * (Radicals - 1) * ... * (Radicals - (Length - 3))`
+		let radicals = Config::current().num_of_radicals();
+
+		return (0..self.as_int().saturating_sub(2)).fold(1, |pages, position| return pages.pmul(radicals.psub(position)));
 	}
 
 	/// Number of concepts in a page.
@@ -60,39 +83,33 @@ impl Length {
 	/// Number of rows in a page.
 	#[must_use]
 	pub fn rows_per_page(self) -> usize {
+		// the second to last radical position decides the row, a single radical only fills one row
 		return match self {
-			Self::L2 => NUM_OF_RADICALS,
-			// `Radicals - 1`
-			Self::L3 => NUM_OF_RADICALS.psub(1),
-			// `Radicals - 2`
-			Self::L4 => NUM_OF_RADICALS.psub(2),
+			Self::L1 => 1,
+			// `Radicals - (Length - 2)`
+			_ => Config::current().num_of_radicals().psub(self.as_int().psub(2)),
 		};
 	}
 
 	/// Number of concepts in a row.
 	#[must_use]
 	pub fn concepts_per_row(self) -> usize {
-		return match self {
-			// `Radicals - 1`
-			Self::L2 => NUM_OF_RADICALS.psub(1),
-			// `Radicals - 2`
-			Self::L3 => NUM_OF_RADICALS.psub(2),
-			// `Radicals - 3`
-			Self::L4 => NUM_OF_RADICALS.psub(3),
-		};
+		// the last radical position decides the concept in a row
+		// `Radicals - (Length - 1)`
+		return Config::current().num_of_radicals().psub(self.as_int().psub(1));
 	}
 
 	/// Total number of concepts.
 	#[must_use]
 	pub fn num_of_concepts(self) -> usize {
-		// `ConceptsPerPage * NumberOfPages`
-		return self.num_of_pages().pmul(self.concepts_per_page());
+		// `ConceptsPerPage * NumberOfPages`, calculated once for the radical inventory
+		return Config::current().ranking(self).concepts();
 	}
 
 	/// Number of stems in a concept.
 	#[must_use]
 	pub fn stems_per_concept(self) -> usize {
-		return structure::list(self).len();
+		return Config::current().templates(self).len();
 	}
 
 	/// Number of words in a stem.
@@ -101,50 +118,43 @@ impl Length {
 		return self.num_of_forms();
 	}
 
+	/// Total number of words.
+	#[must_use]
+	pub fn num_of_words(self) -> usize {
+		// `NumberOfConcepts * StemsPerConcept * WordsPerStem`
+		return self.num_of_concepts().pmul(self.stems_per_concept()).pmul(self.words_per_stem());
+	}
+
 	/// Number of forms.
 	#[must_use]
 	pub fn num_of_forms(self) -> usize {
-		return match self {
-			Self::L2 => 2,
-			Self::L3 | Self::L4 => 4,
-		};
+		return Config::current().forms(self).len();
 	}
 
-	/// Interval at which each radical position changes when iterated.
-	pub(crate) fn radical_intervals(self) -> ArrayVec<[usize; 4]> {
-		let mut intervals = ArrayVec::new();
-
-		match self {
-			Self::L2 => intervals.try_extend_from_slice(&[NUM_OF_RADICALS.psub(1), 1]),
-			Self::L3 => intervals.try_extend_from_slice(&[(NUM_OF_RADICALS.psub(2)).pmul(NUM_OF_RADICALS.psub(1)), NUM_OF_RADICALS.psub(2), 1]),
-			Self::L4 => intervals.try_extend_from_slice(&[
-				(NUM_OF_RADICALS.psub(3)).pmul(NUM_OF_RADICALS.psub(2)).pmul(NUM_OF_RADICALS.psub(1)),
-				(NUM_OF_RADICALS.psub(3)).pmul(NUM_OF_RADICALS.psub(2)),
-				NUM_OF_RADICALS.psub(3),
-				1,
-			]),
-		}
-		.expect("failed to fill stem config internals");
-
-		return intervals;
+	/// Position in [`Length::all`].
+	pub(crate) fn position(self) -> usize {
+		return self.as_int().psub(1);
 	}
 
 	/// List of positions radicals are ordered in inside a concept.
-	pub(crate) fn radical_order(self) -> ArrayVec<[u8; 4]> {
+	pub(crate) fn radical_order(self) -> ArrayVec<[u8; MAX_LENGTH]> {
 		let mut order = ArrayVec::new();
+		let length: u8 = self.as_int().pinto();
 
-		match self {
-			Self::L2 => order.try_extend_from_slice(&[1, 0]),
-			Self::L3 => order.try_extend_from_slice(&[1, 2, 0]),
-			Self::L4 => order.try_extend_from_slice(&[1, 3, 2, 0]),
+		// the first radical in a concept is iterated second, the ones in the middle are iterated last to first and the last radical first
+		// e.g. `[1, 3, 2, 0]` for four radicals
+		if length > 1 {
+			order.push(1);
+			order.extend((2..length).rev());
 		}
-		.expect("failed to fill stem config internals");
+
+		order.push(0);
 
 		return order;
 	}
 
 	/// On some occasions we need the [`Length::radical_order`] mirrored to reflect reverse usage.
-	pub(crate) fn radical_order_mirrored(self) -> ArrayVec<[u8; 4]> {
+	pub(crate) fn radical_order_mirrored(self) -> ArrayVec<[u8; MAX_LENGTH]> {
 		let mut order = ArrayVec::new();
 		let radical_order = self.radical_order();
 
@@ -163,28 +173,35 @@ impl Length {
 		return order;
 	}
 
-	/// Where radicals start on each position in the concept.
-	fn radical_start(self) -> ArrayVec<[u8; 4]> {
-		let mut order = ArrayVec::new();
+	/// Where radicals start on each position when iterated, `radicals` is the radical inventory storing [`Radical::id`]s.
+	/// Takes the inventory instead of reading it from [`Config::current`], because [`Config`] builds its [`Ranking`]s with it.
+	fn radical_start(self, radicals: &[u8]) -> ArrayVec<[u8; MAX_LENGTH]> {
+		let length = self.as_int();
+		let start = radicals.iter().position(|radical| return *radical == START_ID);
 
-		match self {
-			Self::L2 => order.try_extend_from_slice(&[3, 0]),
-			Self::L3 => order.try_extend_from_slice(&[3, 0, 1]),
-			Self::L4 => order.try_extend_from_slice(&[3, 0, 2, 1]),
-		}
-		.expect("failed to fill stem config internals");
-
-		return order;
+		return self
+			.radical_order_mirrored()
+			.into_iter()
+			.map(|position| {
+				// the last radical starts at `h`, all others at the radical with the index of their position in the concept
+				// without `h` in the inventory the last radical starts like all others
+				match start {
+					Some(start) if length > 1 && usize::from(position) == length.psub(1) => return start.pinto(),
+					_ => return position,
+				}
+			})
+			.collect();
 	}
 
 	/// Iterator over [`Radical`]s with the correct ordering depending on position in concept.
 	/// Filter [`Radical`]s through `filter`.
 	pub(crate) fn radicals_ordered<'a>(self, position: usize, filter: &'a [Radical]) -> impl 'a + Iterator<Item = Radical> {
-		// by accident the positions at where radicals start are also the mirrored positions of radicals in a concept
-		let start = self.radical_start()[position];
+		let start = usize::from(self.radical_start(Config::current().radicals())[position]);
 
 		// create ordered iterator
-		return (start..NUM_OF_RADICALS.pinto()).chain(0..start).filter_map(move |value| {
+		return (start..Config::current().num_of_radicals()).chain(0..start).filter_map(move |value| {
+			let value: u8 = value.pinto();
+
 			// remove all used radicals
 			for radical in filter.iter() {
 				if value == radical.index() {
@@ -193,7 +210,7 @@ impl Length {
 			}
 
 			// map radical indexes to `Radical`
-			return Some(Radical::from_index(value));
+			return Some(Radical::new(value));
 		});
 	}
 }
@@ -207,14 +224,165 @@ impl Default for Length {
 
 impl Display for Length {
 	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
-		return write!(
-			formatter,
-			"{}",
-			match self {
-				Self::L2 => 2,
-				Self::L3 => 3,
-				Self::L4 => 4,
+		return write!(formatter, "{}", self.as_int());
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<Length> for usize {
+	fn from(length: Length) -> Self {
+		return length.as_int();
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<usize> for Length {
+	type Error = Error;
+
+	fn try_from(length: usize) -> Result<Self> {
+		return Self::new(length);
+	}
+}
+
+/// Tables to rank and unrank [`Concept`]s of a [`Length`] without iterating over radicals, cached by [`Config`] for its radical inventory.
+#[derive(Clone, Debug)]
+pub(crate) struct Ranking {
+	/// Number of radicals in the radical inventory.
+	radicals: usize,
+	/// Total number of concepts.
+	concepts: usize,
+	/// Interval at which each radical position changes when iterated.
+	intervals: ArrayVec<[usize; MAX_LENGTH]>,
+	/// See [`Length::radical_order`].
+	order: ArrayVec<[u8; MAX_LENGTH]>,
+	/// See [`Length::radical_order_mirrored`].
+	order_mirrored: ArrayVec<[u8; MAX_LENGTH]>,
+	/// See [`Length::radical_start`].
+	start: ArrayVec<[u8; MAX_LENGTH]>,
+}
+
+impl Ranking {
+	/// Build [`Ranking`] for `length` and the radical inventory `radicals`, storing [`Radical::id`]s.
+	pub(crate) fn new(length: Length, inventory: &[u8]) -> Self {
+		let radicals = inventory.len();
+		// each position changes after all combinations of the positions after it, which can't reuse radicals before them
+		// `(Radicals - (Position + 1)) * ... * (Radicals - (Length - 1))`
+		let intervals = (0..length.as_int())
+			.map(|position| {
+				return (position.padd(1)..length.as_int()).fold(1, |interval, position| return interval.pmul(radicals.psub(position)));
+			})
+			.collect();
+
+		return Self {
+			radicals,
+			// `Radicals * (Radicals - 1) * ... * (Radicals - (Length - 1))`
+			concepts: (0..length.as_int()).fold(1, |concepts, position| return concepts.pmul(radicals.psub(position))),
+			intervals,
+			order: length.radical_order(),
+			order_mirrored: length.radical_order_mirrored(),
+			start: length.radical_start(inventory),
+		};
+	}
+
+	/// Get total number of concepts.
+	pub(crate) const fn concepts(&self) -> usize {
+		return self.concepts;
+	}
+
+	/// Get concept index of `radicals`, given in the order they appear in the concept.
+	pub(crate) fn rank(&self, radicals: &[Radical]) -> usize {
+		let mut index = 0;
+		let mut used = ArrayVec::<[_; MAX_LENGTH]>::new();
+
+		for (position, order) in self.order_mirrored.iter().enumerate() {
+			let radical = radicals[usize::from(*order)];
+			let rotated = self.rotate(position, radical);
+			// position in `Length::radicals_ordered`, every used radical before it is skipped
+			let rank = rotated.psub(used.iter().filter(|used| return self.rotate(position, **used) < rotated).count());
+
+			index = index.padd(rank.pmul(self.intervals[position]));
+			used.push(radical);
+		}
+
+		return index;
+	}
+
+	/// Get radicals of the concept at `index` in the order they appear in the concept, `index` has to be lower then [`Ranking::concepts`].
+	pub(crate) fn unrank(&self, index: usize) -> [Radical; MAX_LENGTH] {
+		let mut used = ArrayVec::<[_; MAX_LENGTH]>::new();
+		let mut index_left = index;
+
+		for (position, interval) in self.intervals.iter().enumerate() {
+			let rank = index_left.pdiv(*interval);
+			index_left = index_left.psub(rank.pmul(*interval));
+
+			// every used radical at or before the rank pushes it one further, which can push it past more used radicals
+			let mut rotated = rank;
+
+			loop {
+				let passed = used.iter().filter(|used| return self.rotate(position, **used) <= rotated).count();
+
+				if rank.padd(passed) == rotated {
+					break;
+				}
+
+				rotated = rank.padd(passed);
 			}
-		);
+
+			let unrotated = rotated.padd(usize::from(self.start[position]));
+
+			used.push(Radical::new(
+				if unrotated >= self.radicals {
+					unrotated.psub(self.radicals)
+				} else {
+					unrotated
+				}
+				.pinto(),
+			));
+		}
+
+		let mut radicals = [Radical::new(0); MAX_LENGTH];
+
+		for (radical, order) in radicals.iter_mut().zip(&self.order) {
+			*radical = used[usize::from(*order)];
+		}
+
+		return radicals;
+	}
+
+	/// Change `radicals` to the concept following it in the same [`Row`], only the radical iterated last changes.
+	pub(crate) fn next_in_row(&self, radicals: &mut [Radical; MAX_LENGTH]) {
+		let length = self.order.len();
+		let position = usize::from(self.order_mirrored[length.psub(1)]);
+		let mut index = usize::from(radicals[position].index());
+
+		// the next radical in the inventory that isn't used yet, rows never wrap around to their first radical
+		loop {
+			index = index.padd(1);
+
+			if index == self.radicals {
+				index = 0;
+			}
+
+			let radical = Radical::new(index.pinto());
+
+			if !radicals[..length].contains(&radical) {
+				radicals[position] = radical;
+				return;
+			}
+		}
+	}
+
+	/// Get position of `radical` after rotating the radical inventory to start where it does at `position`.
+	fn rotate(&self, position: usize, radical: Radical) -> usize {
+		let start = usize::from(self.start[position]);
+		let index = usize::from(radical.index());
+
+		// radicals before `start` wrap around to the end, cheaper then calculating the remainder
+		if index >= start {
+			return index.psub(start);
+		} else {
+			return index.padd(self.radicals).psub(start);
+		}
 	}
 }
@@ -0,0 +1,133 @@
+//! Unicode normalization and lenient parsing of look-alike characters.
+
+use crate::*;
+use std::borrow::Cow;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+/// Maximum number of look-alikes that are tried in one string, more are left as they are.
+const MAX_SUBSTITUTIONS: usize = 8;
+
+/// Look-alike replaced by its canonical string, see [`Lenient::substitutions`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Substitution {
+	/// Byte position in the normalized string.
+	position: usize,
+	/// Look-alike found in the string.
+	from: &'static str,
+	/// Canonical string it was replaced with.
+	to: &'static str,
+}
+
+impl Substitution {
+	/// Get byte position in the string after normalizing it to NFC.
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		return self.position;
+	}
+
+	/// Get look-alike found in the string.
+	#[must_use]
+	pub const fn from(&self) -> &'static str {
+		return self.from;
+	}
+
+	/// Get canonical string it was replaced with.
+	#[must_use]
+	pub const fn to(&self) -> &'static str {
+		return self.to;
+	}
+}
+
+/// Return type from [`Script::from_str_lenient`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lenient {
+	/// Parsed [`Concept`] or [`Word`].
+	parsed: Parsed,
+	/// Look-alikes that had to be replaced.
+	substitutions: Vec<Substitution>,
+}
+
+impl Lenient {
+	/// Get [`Parsed`].
+	#[must_use]
+	pub const fn parsed(&self) -> Parsed {
+		return self.parsed;
+	}
+
+	/// Get [`Substitution`]s that had to be applied, empty if the string could be read as it is.
+	#[must_use]
+	pub fn substitutions(&self) -> &[Substitution] {
+		return &self.substitutions;
+	}
+}
+
+/// Normalize `string` to NFC, the form all built-in scripts are written in.
+pub(crate) fn normalize(string: &str) -> Cow<'_, str> {
+	if is_nfc_quick(string.chars()) == IsNormalized::Yes {
+		return Cow::Borrowed(string);
+	} else {
+		return Cow::Owned(string.nfc().collect());
+	}
+}
+
+/// Build [`Concept`] or [`Word`] from string, replacing look-alikes if needed, see [`Script::from_str_lenient`].
+pub(crate) fn from_str(script: Script, string: &str) -> Result<Lenient> {
+	let string = normalize(string);
+
+	let error = match script.as_ref().from_str(&string) {
+		Ok(parsed) => {
+			return Ok(Lenient {
+				parsed,
+				substitutions: Vec::new(),
+			})
+		},
+		Err(error) => error,
+	};
+
+	// find look-alikes, the longest one wins if more then one starts at the same position
+	let confusables = script.orthography().map_or(&[][..], |orthography| return orthography.confusables());
+	let mut found = Vec::new();
+	let mut position = 0;
+
+	while position < string.len() && found.len() < MAX_SUBSTITUTIONS {
+		if let Some((from, to)) = confusables
+			.iter()
+			.filter(|(from, _)| return string[position..].starts_with(from))
+			.max_by_key(|(from, _)| return from.len())
+		{
+			found.push(Substitution { position, from, to });
+			position = position.padd(from.len());
+		} else {
+			position = position.padd(string[position..].chars().next().map_or(1, char::len_utf8));
+		}
+	}
+
+	// a look-alike can be a valid character itself, so try as few substitutions as possible first
+	let mut subsets: Vec<u32> = (1..1_u32 << found.len()).collect();
+	subsets.sort_by_key(|subset| return subset.count_ones());
+
+	for subset in subsets {
+		let substitutions: Vec<_> = found
+			.iter()
+			.enumerate()
+			.filter(|(index, _)| return subset & (1 << index) != 0)
+			.map(|(_, substitution)| return substitution.clone())
+			.collect();
+		let mut substituted = String::with_capacity(string.len());
+		let mut position = 0;
+
+		for substitution in &substitutions {
+			substituted.push_str(&string[position..substitution.position]);
+			substituted.push_str(substitution.to);
+			position = substitution.position.padd(substitution.from.len());
+		}
+
+		substituted.push_str(&string[position..]);
+
+		if let Ok(parsed) = script.as_ref().from_str(&substituted) {
+			return Ok(Lenient { parsed, substitutions });
+		}
+	}
+
+	return Err(error);
+}
@@ -14,28 +14,56 @@
 
 //! UYWI word generator.
 
+mod collation;
+mod collision;
+mod completion;
 mod concept;
+mod config;
+mod derivation;
+mod features;
 mod length;
+mod lenient;
 mod page;
+mod query;
+mod registry;
 mod row;
+mod rules;
 mod script;
 mod stem;
 mod structure;
+mod suggestion;
+mod syllable;
+mod text;
 mod util;
 mod word;
 
-pub use concept::Concept;
-pub use length::Length;
+pub use arrayvec;
+pub use collation::Collation;
+pub use collision::{Collision, Collisions};
+pub use completion::{Completion, Completions};
+pub use concept::{Concept, Radical};
+pub use config::Config;
+pub use derivation::{Assimilation, Change, Derivation, Step};
+pub use features::{Articulation, Backness, Coloring, Height, Manner, Place, RadicalFeatures, Voicing, VowelFeatures};
+pub use length::{Length, MAX_LENGTH};
+pub use lenient::{Lenient, Substitution};
 pub use page::{Page, Pages};
-pub use script::Script;
+pub use query::Query;
+pub use registry::ScriptRegistry;
+pub use rules::{Action, Context, Feature, Pattern, Rule, Rules, Side};
+#[cfg(feature = "definition")]
+pub use script::ScriptDefinition;
+pub use script::{ConceptOrWord, Extension, Lettering, Orthography, Parsed, Phonology, Script, CONCEPT_BUFFER, NUM_OF_RADICALS, WORD_BUFFER};
+pub use structure::{Form, FormVowel, Letter, StemTemplate, Vowel};
+pub use suggestion::{Mistake, Suggestion, Suggestions};
+pub use syllable::{Stress, Syllable};
+pub use text::{tokenize, transliterate, Token, TokenKind, Transliteration};
+pub use word::Word;
 
-pub(crate) use concept::Radical;
 pub(crate) use row::{Row, Rows};
-pub(crate) use script::{Extension, NUM_OF_RADICALS};
 pub(crate) use stem::{Stem, Stems};
-pub(crate) use structure::{Letter, Vowel};
 pub(crate) use util::*;
-pub(crate) use word::{Word, Words};
+pub(crate) use word::Words;
 
 use thiserror::Error as ThisError;
 
@@ -72,10 +100,106 @@ pub enum Error {
 	/// Error when string to build a [`Concept`] had invalid letters.
 	#[error("Concept contains invalid letters.")]
 	ConceptRadicalInvalid,
+	/// Error when stem index to build a [`Word`] was invalid.
+	#[error("Index of stem is invalid.")]
+	StemIndexInvalid,
+	/// Error when form index to build a [`Word`] was invalid.
+	#[error("Index of form is invalid.")]
+	FormIndexInvalid,
+	/// Error when index to build a [`Word`] was invalid.
+	#[error("Index of word is invalid.")]
+	WordIndexInvalid,
+	/// Error when index string to build a [`Word`] was invalid.
+	#[error("String of word index is invalid.")]
+	WordStringInvalid,
+	/// Error when index string to build a [`Word`] contained 0.
+	#[error("String of word index contains 0.")]
+	WordStringNull,
 	/// Error when string to build a [`Word`] was invalid.
 	#[error("Word invalid")]
 	WordInvalid,
 	/// Error when string to build a [`Word`] was bigger then buffer.
 	#[error("Word length is invalid")]
 	WordLengthInvalid,
+	/// Error when a [`StemTemplate`] contained no letters.
+	#[error("Stem template is empty.")]
+	TemplateEmpty,
+	/// Error when a [`StemTemplate`] contained more letters then supported.
+	#[error("Stem template has more then 8 letters.")]
+	TemplateTooLong,
+	/// Error when a letter in a [`StemTemplate`] was invalid.
+	#[error("Letter {0} of stem template is invalid.")]
+	TemplateLetterInvalid(usize),
+	/// Error when a duplicate consonant in a [`StemTemplate`] came before its consonant.
+	#[error("Duplicate consonant at letter {0} of stem template comes before its consonant.")]
+	TemplateDuplicateInvalid(usize),
+	/// Error when a consonant in a [`StemTemplate`] was higher then the [`Length`] allows.
+	#[error("Consonant at letter {0} of stem template is higher then the length allows.")]
+	TemplateConsonantInvalid(usize),
+	/// Error when a consonant was used twice in a [`StemTemplate`].
+	#[error("Consonant {0} is used twice in stem template.")]
+	TemplateConsonantDuplicate(usize),
+	/// Error when a consonant was missing in a [`StemTemplate`].
+	#[error("Consonant {0} is missing in stem template.")]
+	TemplateConsonantMissing(usize),
+	/// Error when a [`Length`] was configured without [`StemTemplate`]s.
+	#[error("Stem templates are empty.")]
+	TemplatesEmpty,
+	/// Error when a [`Length`] was configured with more [`StemTemplate`]s then supported.
+	#[error("More then 255 stem templates.")]
+	TemplatesTooMany,
+	/// Error when a [`Config`] was installed after one was already installed or used.
+	#[error("Config is already installed.")]
+	ConfigInstalled,
+	/// Error when the radical inventory had less radicals then [`MAX_LENGTH`].
+	#[error("Less radicals then the maximum concept length.")]
+	RadicalsTooFew,
+	/// Error when the radical inventory had more radicals then [`NUM_OF_RADICALS`].
+	#[error("More radicals then the built-in scripts know.")]
+	RadicalsTooMany,
+	/// Error when the radical inventory contained a radical the built-in scripts don't know.
+	#[error("Radical inventory contains an unknown radical.")]
+	RadicalsInvalid,
+	/// Error when the radical inventory contained a radical twice.
+	#[error("Radical is used twice in radical inventory.")]
+	RadicalsDuplicate,
+	/// Error when a [`Form`] or [`FormVowel`] was invalid.
+	#[error("Form is invalid.")]
+	FormVowelInvalid,
+	/// Error when a [`Length`] was configured without [`Form`]s.
+	#[error("Forms are empty.")]
+	FormsEmpty,
+	/// Error when a [`Length`] was configured with more [`Form`]s then supported.
+	#[error("More then 255 forms.")]
+	FormsTooMany,
+	/// Error when a [`Script`] with the same name was already registered.
+	#[error("Script name is already registered.")]
+	ScriptNameDuplicate,
+	/// Error when no built-in [`Script`] has the given name.
+	#[error("Script name is unknown.")]
+	ScriptNameUnknown,
+	/// Error when a script definition couldn't be read.
+	#[error("Script definition is invalid.")]
+	ScriptDefinitionInvalid,
+	/// Error when a script definition had the wrong number of radicals or invalid radicals.
+	#[error("Script radicals are invalid.")]
+	ScriptRadicalsInvalid,
+	/// Error when a script definition had radicals that make concepts ambiguous.
+	#[error("Script radicals are ambiguous.")]
+	ScriptRadicalDuplicate,
+	/// Error when a script definition had missing or invalid forms.
+	#[error("Script forms are invalid.")]
+	ScriptFormsInvalid,
+	/// Error when a [`Pattern`] had an unknown [`Feature`].
+	#[error("Feature is invalid.")]
+	FeatureInvalid,
+	/// Error when a [`Rule`] moves a segment without a [`Context`] to move it from.
+	#[error("Rule moves a segment without a context to move it from.")]
+	RuleMoveInvalid,
+	/// Error when [`Rules`] couldn't be read.
+	#[error("Rules are invalid.")]
+	RulesInvalid,
+	/// Error when a [`Query`] has a misplaced anchor, an unclosed class or a trailing escape.
+	#[error("Query is invalid at position {0}.")]
+	QueryInvalid(usize),
 }
@@ -1,6 +1,10 @@
 //! UYWI pages.
 
 use crate::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 
 /// All pages, only used for iteration.
@@ -40,6 +44,11 @@ impl Iterator for Pages {
 
 /// A page, used for type checking.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(Deserialize, Serialize),
+	serde(into = "(Length, usize)", try_from = "(Length, usize)")
+)]
 pub struct Page {
 	/// Page index.
 	index: usize,
@@ -106,3 +115,19 @@ impl Display for Page {
 		return write!(formatter, "{}", self.index.padd(1));
 	}
 }
+
+#[cfg(feature = "serde")]
+impl From<Page> for (Length, usize) {
+	fn from(page: Page) -> Self {
+		return (page.length, page.index);
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<(Length, usize)> for Page {
+	type Error = Error;
+
+	fn try_from((length, index): (Length, usize)) -> Result<Self> {
+		return Self::from_index(index, length);
+	}
+}
@@ -0,0 +1,328 @@
+//! Pattern search over [`Concept`]s and [`Word`]s.
+
+use crate::*;
+use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Part of a [`Query`].
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+	/// Letters written exactly like this.
+	Text(String),
+	/// `?`: any letter.
+	Any,
+	/// `*`: any number of letters, including none.
+	Many,
+	/// `[…]`: any letter matching the [`Pattern`].
+	Class(Pattern),
+}
+
+/// Search pattern over the strings of [`Concept`]s and [`Word`]s in a [`Script`].
+///
+/// - `?`: any letter
+/// - `*`: any number of letters, including none
+/// - `[consonant dark]`: any letter matching a [`Pattern`], see [`Feature`] for the names, e.g. `[vowel]` or `[!voiceless consonant]`
+/// - `^` at the start and `$` at the end: anchor the query to the start or end, otherwise it can match anywhere
+/// - `\`: write the next character as it is, e.g. `\?` for the UYWI Chiffre radical `?`
+/// - everything else is matched as it is written, but only as whole letters, e.g. `s` doesn't match the start of the Latin `sh`
+///
+/// Letters are what the [`Script`] writes for every letter of the stem template, see [`Lettering::letters`], and every radical of a concept.
+/// Only [`Feature`]s of the letter itself are known, e.g. `dark` matches the letters of dark radicals, but no vowels made dark by them.
+/// Like in [`Rule`]s, `alternative`, `beginning` and `end` match radicals wherever they are, `removed` never matches.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+	/// [`Script`] the query is written in.
+	script: Script,
+	/// Anchored at the start.
+	start: bool,
+	/// Anchored at the end.
+	end: bool,
+	/// Tokens.
+	tokens: Vec<Token>,
+	/// Radicals every match has to contain.
+	radicals: Vec<Radical>,
+	/// Classes that only radicals can match, every match has to contain a radical matching each one.
+	classes: Vec<Pattern>,
+}
+
+impl Query {
+	/// Build [`Query`] from `query` written in `script`.
+	/// # Errors
+	/// - [`Error::QueryInvalid`] if an anchor, a class or an escape is invalid, together with its byte position
+	/// - [`Error::FeatureInvalid`] if a class has an unknown [`Feature`]
+	pub fn new(script: Script, query: &str) -> Result<Self> {
+		let query = lenient::normalize(query);
+		let mut tokens = Vec::new();
+		let mut start = false;
+		let mut end = false;
+		let mut text = String::new();
+		let mut chars = query.char_indices().peekable();
+
+		while let Some((position, char)) = chars.next() {
+			let token = match char {
+				'^' if position == 0 => {
+					start = true;
+					continue;
+				},
+				'$' if chars.peek().is_none() => {
+					end = true;
+					continue;
+				},
+				'^' | '$' => return Err(Error::QueryInvalid(position)),
+				'?' => Token::Any,
+				'*' => Token::Many,
+				'[' => {
+					let close = query[position..].find(']').ok_or(Error::QueryInvalid(position))?;
+					let pattern = Pattern::from_str(&query[position.padd(1)..position.padd(close)])?;
+
+					if pattern.is_boundary() {
+						return Err(Error::QueryInvalid(position));
+					}
+
+					while chars.next_if(|(next, _)| return *next <= position.padd(close)).is_some() {}
+
+					Token::Class(pattern)
+				},
+				'\\' => {
+					let (_, char) = chars.next().ok_or(Error::QueryInvalid(position))?;
+					text.push(char);
+					continue;
+				},
+				char => {
+					text.push(char);
+					continue;
+				},
+			};
+
+			if !text.is_empty() {
+				tokens.push(Token::Text(text.split_off(0)));
+			}
+
+			// more then one `*` in a row don't change anything
+			if !(token == Token::Many && tokens.last() == Some(&Token::Many)) {
+				tokens.push(token);
+			}
+		}
+
+		if !text.is_empty() {
+			tokens.push(Token::Text(text));
+		}
+
+		let mut radicals = Vec::new();
+		let mut classes = Vec::new();
+
+		for token in &tokens {
+			match token {
+				Token::Text(text) => {
+					if let Some(lettering) = script.lettering() {
+						radicals.extend(lettering.required_radicals(text));
+					}
+				},
+				// classes that can't match a vowel or a duplicate vowel can only match a radical
+				Token::Class(pattern)
+					if [
+						Letter::Vowel(Vowel::First),
+						Letter::Nasal(Vowel::First),
+						Letter::DuplicateVowel(Vowel::First),
+					]
+					.iter()
+					.all(|letter| return !pattern.matches_features(|feature| return has(*letter, None, feature))) =>
+				{
+					classes.push(pattern.clone())
+				},
+				_ => (),
+			}
+		}
+
+		return Ok(Self {
+			script,
+			start,
+			end,
+			tokens,
+			radicals,
+			classes,
+		});
+	}
+
+	/// Get [`Script`] the query is written in.
+	#[must_use]
+	pub const fn script(&self) -> Script {
+		return self.script;
+	}
+
+	/// Check if `concept` matches.
+	#[must_use]
+	pub fn matches_concept(&self, concept: Concept) -> bool {
+		if !self.could_match(concept) {
+			return false;
+		}
+
+		let letters: Vec<_> = concept
+			.radicals()
+			.iter()
+			.enumerate()
+			.map(|(index, radical)| return (radical_letter(self.script, *radical), Letter::Consonant(index.pinto()), Some(*radical)))
+			.collect();
+
+		return self.matches(&letters);
+	}
+
+	/// Check if `word` matches.
+	#[must_use]
+	pub fn matches_word(&self, word: Word) -> bool {
+		if !self.could_match(word.concept()) {
+			return false;
+		}
+
+		let structure = word.structure();
+		let radicals = word.concept().radicals();
+		let texts = if let Some(texts) = letters(self.script, word) {
+			texts
+		} else {
+			return false;
+		};
+
+		let letters: Vec<_> = structure
+			.iter()
+			.zip(texts)
+			.map(|(letter, text)| {
+				let radical = match letter {
+					Letter::Consonant(index) | Letter::DuplicateConsonant(index) => Some(radicals[usize::from(*index)]),
+					Letter::Vowel(..) | Letter::DuplicateVowel(..) | Letter::Nasal(..) => None,
+				};
+
+				return (text, *letter, radical);
+			})
+			.collect();
+
+		return self.matches(&letters);
+	}
+
+	/// Iterator over all [`Concept`]s of `length` that match, in index order.
+	pub fn concepts(&self, length: Length) -> impl '_ + Iterator<Item = Concept> {
+		return Pages::new(length)
+			.flat_map(|page| return page.into_iter().flatten())
+			.filter(move |concept| return self.matches_concept(*concept));
+	}
+
+	/// Iterator over all [`Word`]s of `length` that match, in index order.
+	/// Concepts that lack a radical the query needs are skipped without building their words.
+	pub fn words(&self, length: Length) -> impl '_ + Iterator<Item = Word> {
+		return Pages::new(length)
+			.flat_map(|page| return page.into_iter().flatten())
+			.filter(move |concept| return self.could_match(*concept))
+			.flat_map(|concept| return concept.into_iter().flatten())
+			.filter(move |word| return self.matches_word(*word));
+	}
+
+	/// Check if `concept` has every radical the query needs, without building any strings.
+	fn could_match(&self, concept: Concept) -> bool {
+		let radicals = concept.radicals();
+
+		return self.radicals.iter().all(|radical| return radicals.contains(radical))
+			&& self.classes.iter().all(|pattern| {
+				return radicals.iter().any(|radical| {
+					return [Letter::Consonant(0), Letter::DuplicateConsonant(0)]
+						.iter()
+						.any(|letter| return pattern.matches_features(|feature| return has(*letter, Some(*radical), feature)));
+				});
+			});
+	}
+
+	/// Check if the letters match, anywhere if the query isn't anchored at the start.
+	fn matches(&self, letters: &[(String, Letter, Option<Radical>)]) -> bool {
+		// letters that aren't written can't be matched
+		let letters: Vec<_> = letters.iter().filter(|(text, ..)| return !text.is_empty()).collect();
+
+		if self.start {
+			return self.matches_at(&self.tokens, &letters);
+		} else {
+			return (0..=letters.len()).any(|start| return self.matches_at(&self.tokens, &letters[start..]));
+		}
+	}
+
+	/// Check if `tokens` match the start of `letters`.
+	fn matches_at(&self, tokens: &[Token], letters: &[&(String, Letter, Option<Radical>)]) -> bool {
+		let (token, tokens_left) = if let Some(split) = tokens.split_first() {
+			split
+		} else {
+			return !self.end || letters.is_empty();
+		};
+
+		return match token {
+			Token::Many => (0..=letters.len()).any(|skip| return self.matches_at(tokens_left, &letters[skip..])),
+			Token::Any => !letters.is_empty() && self.matches_at(tokens_left, &letters[1..]),
+			Token::Class(pattern) => {
+				letters.first().map_or(false, |(_, letter, radical)| {
+					return pattern.matches_features(|feature| return has(*letter, *radical, feature));
+				}) && self.matches_at(tokens_left, &letters[1..])
+			},
+			Token::Text(text) => {
+				// text has to be made of whole letters
+				let mut text_left = text.as_str();
+				let mut count = 0;
+
+				while !text_left.is_empty() {
+					if let Some(left) = letters.get(count).and_then(|(text, ..)| return text_left.strip_prefix(text.as_str())) {
+						text_left = left;
+						count = count.padd(1);
+					} else {
+						return false;
+					}
+				}
+
+				self.matches_at(tokens_left, &letters[count..])
+			},
+		};
+	}
+}
+
+/// Get what `script` writes for every letter of the stem template of `word`, see [`Lettering::letters`].
+/// Falls back to the segments of [`Phonology::derivation`] and then to one grapheme per letter if the script can't tell,
+/// [`None`] if that doesn't add up either.
+pub(crate) fn letters(script: Script, word: Word) -> Option<Vec<String>> {
+	let length = word.structure().len();
+	let letters = script.lettering().map(|lettering| return lettering.letters(word)).or_else(|| {
+		return script.phonology().map(|phonology| return phonology.derivation(word).segments().to_vec());
+	});
+
+	return match letters {
+		Some(letters) if letters.len() == length => Some(letters),
+		_ => {
+			let string = word.to_string(script);
+			let graphemes: Vec<_> = string.graphemes(true).map(str::to_owned).collect();
+
+			if graphemes.len() == length {
+				Some(graphemes)
+			} else {
+				None
+			}
+		},
+	};
+}
+
+/// Get what `script` writes for `radical` in a [`Concept`].
+pub(crate) fn radical_letter(script: Script, radical: Radical) -> String {
+	return Concept::from_radicals(&[radical]).map_or_else(|_| return String::new(), |concept| return concept.to_string(script));
+}
+
+/// Check if a letter of the stem template has a [`Feature`], radical features come from `radical`.
+fn has(letter: Letter, radical: Option<Radical>, feature: Feature) -> bool {
+	return match feature {
+		Feature::Consonant => matches!(letter, Letter::Consonant(..)),
+		Feature::Vowel => matches!(letter, Letter::Vowel(..) | Letter::Nasal(..)),
+		Feature::Duplicate => matches!(letter, Letter::DuplicateConsonant(..) | Letter::DuplicateVowel(..)),
+		Feature::Nasal => matches!(letter, Letter::Nasal(..)),
+		Feature::Dark => radical.map_or(false, |radical| return radical.features().is_dark()),
+		Feature::Light => radical.map_or(false, |radical| return radical.features().is_light()),
+		Feature::Shading => radical.map_or(false, |radical| return radical.features().is_shading()),
+		Feature::Rounding => radical.map_or(false, |radical| return radical.features().is_rounding()),
+		Feature::Voiceless => radical.map_or(false, |radical| return radical.features().is_voiceless()),
+		// like in rules, these belong to the radical wherever it is
+		Feature::Alternative | Feature::Beginning | Feature::End => {
+			radical.map_or(false, |radical| return script::radical_has(radical.id(), feature))
+		},
+		// letters that aren't written are never matched
+		Feature::Removed => false,
+	};
+}
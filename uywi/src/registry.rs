@@ -0,0 +1,53 @@
+//! Registry of scripts.
+
+use crate::*;
+
+/// Registry of [`Script`]s keyed by their name.
+/// Starts out with all built-in [`Script`]s.
+#[derive(Clone, Debug)]
+pub struct ScriptRegistry {
+	/// Registered scripts.
+	scripts: Vec<Script>,
+}
+
+impl ScriptRegistry {
+	/// Build new [`ScriptRegistry`] with all built-in [`Script`]s.
+	#[must_use]
+	pub fn new() -> Self {
+		return Self {
+			scripts: vec![Script::UywiChiffre, Script::IpaPeter, Script::XSampa, Script::Latin],
+		};
+	}
+
+	/// Register user-defined script.
+	/// Scripts have to live for the rest of the program, use [`Box::leak`] to register scripts built at runtime.
+	/// # Errors
+	/// [`Error::ScriptNameDuplicate`] if a [`Script`] with the same name is already registered
+	pub fn register(&mut self, extension: &'static dyn Extension) -> Result<Script> {
+		if self.get(extension.name()).is_some() {
+			return Err(Error::ScriptNameDuplicate);
+		}
+
+		let script = Script::Custom(extension);
+		self.scripts.push(script);
+
+		return Ok(script);
+	}
+
+	/// Get [`Script`] by name.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<Script> {
+		return self.scripts.iter().copied().find(|script| return script.name() == name);
+	}
+
+	/// Iterator over all registered [`Script`]s.
+	pub fn iter(&self) -> impl '_ + Iterator<Item = Script> {
+		return self.scripts.iter().copied();
+	}
+}
+
+impl Default for ScriptRegistry {
+	fn default() -> Self {
+		return Self::new();
+	}
+}
@@ -1,6 +1,6 @@
 //! UYWI rows.
 
-use crate::*;
+use crate::{length::Ranking, *};
 
 /// Rows of a page only used for iteration.
 #[derive(Clone, Debug)]
@@ -52,6 +52,12 @@ pub struct Row {
 	start_concept_index: usize,
 	/// Concept length.
 	length: Length,
+	/// Number of concepts in a row, cached for iteration.
+	concepts_per_row: usize,
+	/// Tables to build the first [`Concept`] and step to the next, cached for iteration.
+	ranking: &'static Ranking,
+	/// Radicals of the current [`Concept`], saved for iteration.
+	radicals: [Radical; MAX_LENGTH],
 	/// Current iteration, added to `start_concept_index` to get current [`Concept`].
 	iter: usize,
 }
@@ -60,7 +66,7 @@ impl Row {
 	/// Build [`Row`] from [`Page`] and row index.
 	fn new(index: usize, start_concept_index: usize, length: Length) -> Self {
 		assert!(
-			start_concept_index.padd(length.concepts_per_row()) < length.num_of_concepts(),
+			start_concept_index.padd(length.concepts_per_row()) <= length.num_of_concepts(),
 			"concept index in addition to possible concepts needed is higher than number of existing concepts"
 		);
 		assert!(index < length.rows_per_page(), "row index is higher than number of possible rows");
@@ -69,6 +75,9 @@ impl Row {
 			index,
 			length,
 			start_concept_index: start_concept_index.padd(index.pmul(length.concepts_per_row())),
+			concepts_per_row: length.concepts_per_row(),
+			ranking: Config::current().ranking(length),
+			radicals: [Radical::new(0); MAX_LENGTH],
 			iter: 0,
 		};
 	}
@@ -85,9 +94,15 @@ impl Iterator for Row {
 
 	fn next(&mut self) -> Option<Self::Item> {
 		// check if we reached the end of the row
-		if self.iter < self.length.concepts_per_row() {
-			// `StartConcept + Iter`
-			let concept = Some(Concept::from_index(self.start_concept_index.padd(self.iter), self.length).expect("invalid concept index"));
+		if self.iter < self.concepts_per_row {
+			// only the first concept has to be calculated from its index, checked to be valid when the row was built
+			if self.iter == 0 {
+				self.radicals = self.ranking.unrank(self.start_concept_index);
+			} else {
+				self.ranking.next_in_row(&mut self.radicals);
+			}
+
+			let concept = Some(Concept::new(self.radicals, self.length));
 
 			// increment concept by one
 			self.iter = self.iter.padd(1);
@@ -0,0 +1,602 @@
+//! Phonological rules applied by [`Script::IpaPeter`](crate::Script::IpaPeter).
+
+use crate::*;
+use arrayvec::ArrayVec;
+#[cfg(feature = "definition")]
+use serde::Deserialize;
+#[cfg(feature = "definition")]
+use std::convert::TryFrom;
+use std::{
+	fmt::{self, Display, Formatter},
+	str::FromStr,
+};
+
+/// Feature of a segment, used by [`Pattern`]s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Feature {
+	/// Radical.
+	Consonant,
+	/// Vowel.
+	Vowel,
+	/// Duplicated consonant or vowel.
+	Duplicate,
+	/// Removed segment, it isn't written.
+	Removed,
+	/// Nasal position of the stem template.
+	Nasal,
+	/// Dark radical.
+	Dark,
+	/// Light radical.
+	Light,
+	/// Radical that shades the following vowel.
+	Shading,
+	/// Radical that rounds the following vowel.
+	Rounding,
+	/// Voiceless radical.
+	Voiceless,
+	/// Radical with a voiceless alternative.
+	Alternative,
+	/// Radical that is removed at the beginning of a word.
+	Beginning,
+	/// Radical that is removed at the end of a word.
+	End,
+}
+
+impl Feature {
+	/// All features.
+	#[must_use]
+	pub const fn all() -> [Self; 13] {
+		return [
+			Self::Consonant,
+			Self::Vowel,
+			Self::Duplicate,
+			Self::Removed,
+			Self::Nasal,
+			Self::Dark,
+			Self::Light,
+			Self::Shading,
+			Self::Rounding,
+			Self::Voiceless,
+			Self::Alternative,
+			Self::Beginning,
+			Self::End,
+		];
+	}
+
+	/// Get in `str` form.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		return match self {
+			Self::Consonant => "consonant",
+			Self::Vowel => "vowel",
+			Self::Duplicate => "duplicate",
+			Self::Removed => "removed",
+			Self::Nasal => "nasal",
+			Self::Dark => "dark",
+			Self::Light => "light",
+			Self::Shading => "shading",
+			Self::Rounding => "rounding",
+			Self::Voiceless => "voiceless",
+			Self::Alternative => "alternative",
+			Self::Beginning => "beginning",
+			Self::End => "end",
+		};
+	}
+}
+
+impl FromStr for Feature {
+	type Err = Error;
+
+	fn from_str(string: &str) -> Result<Self> {
+		return Self::all()
+			.iter()
+			.copied()
+			.find(|feature| return feature.as_str() == string)
+			.ok_or(Error::FeatureInvalid);
+	}
+}
+
+impl Display for Feature {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		return write!(formatter, "{}", self.as_str());
+	}
+}
+
+/// Features a segment has to have or lack, or the boundary of the word.
+///
+/// In string form features are separated by whitespace and lacking features start with `!`, e.g. `consonant !dark`.
+/// `#` is the boundary of the word, an empty string matches every segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+	/// Matches the boundary of the word instead of a segment.
+	boundary: bool,
+	/// Features and if they have to be present or absent.
+	features: Vec<(Feature, bool)>,
+}
+
+impl Pattern {
+	/// Build [`Pattern`] matching segments that have all `features` set to `true` and lack all set to `false`.
+	#[must_use]
+	pub fn new(features: Vec<(Feature, bool)>) -> Self {
+		return Self { boundary: false, features };
+	}
+
+	/// Build [`Pattern`] matching the boundary of the word.
+	#[must_use]
+	pub fn boundary() -> Self {
+		return Self {
+			boundary: true,
+			features: Vec::new(),
+		};
+	}
+
+	/// Get if this matches the boundary of the word.
+	#[must_use]
+	pub const fn is_boundary(&self) -> bool {
+		return self.boundary;
+	}
+
+	/// Get features and if they have to be present or absent.
+	#[must_use]
+	pub fn features(&self) -> &[(Feature, bool)] {
+		return &self.features;
+	}
+
+	/// Check if `segment` matches.
+	pub(crate) fn matches(&self, segment: impl Segment) -> bool {
+		return self.matches_features(|feature| return segment.has(feature));
+	}
+
+	/// Check if something with the features `has` reports matches, a boundary never does.
+	pub(crate) fn matches_features(&self, has: impl Fn(Feature) -> bool) -> bool {
+		return !self.boundary && self.features.iter().all(|(feature, present)| return has(*feature) == *present);
+	}
+}
+
+impl FromStr for Pattern {
+	type Err = Error;
+
+	fn from_str(string: &str) -> Result<Self> {
+		if string.trim() == "#" {
+			return Ok(Self::boundary());
+		}
+
+		return string
+			.split_whitespace()
+			.map(|feature| {
+				return if let Some(feature) = feature.strip_prefix('!') {
+					Ok((feature.parse()?, false))
+				} else {
+					Ok((feature.parse()?, true))
+				};
+			})
+			.collect::<Result<_>>()
+			.map(Self::new);
+	}
+}
+
+impl Display for Pattern {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		if self.boundary {
+			return write!(formatter, "#");
+		}
+
+		for (position, (feature, present)) in self.features.iter().enumerate() {
+			if position > 0 {
+				write!(formatter, " ")?;
+			}
+
+			if !present {
+				write!(formatter, "!")?;
+			}
+
+			write!(formatter, "{}", feature)?;
+		}
+
+		return Ok(());
+	}
+}
+
+/// Where a [`Context`] is searched.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "definition", derive(Deserialize), serde(rename_all = "lowercase"))]
+pub enum Side {
+	/// Before the target.
+	Before,
+	/// After the target.
+	After,
+	/// Before or after the target.
+	Around,
+}
+
+/// Segment that has to be next to the target of a [`Rule`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Context {
+	/// Where to search.
+	side: Side,
+	/// Segment to find.
+	pattern: Pattern,
+	/// Segments that are skipped while searching.
+	skip: Option<Pattern>,
+}
+
+impl Context {
+	/// Build [`Context`]: searching from the target to `side`, segments matching `skip` are skipped until one matches `pattern`.
+	#[must_use]
+	pub const fn new(side: Side, pattern: Pattern, skip: Option<Pattern>) -> Self {
+		return Self { side, pattern, skip };
+	}
+
+	/// Get where to search.
+	#[must_use]
+	pub const fn side(&self) -> Side {
+		return self.side;
+	}
+
+	/// Get segment to find.
+	#[must_use]
+	pub const fn pattern(&self) -> &Pattern {
+		return &self.pattern;
+	}
+
+	/// Get segments that are skipped, [`None`] if nothing is skipped.
+	#[must_use]
+	pub const fn skip(&self) -> Option<&Pattern> {
+		return self.skip.as_ref();
+	}
+
+	/// Search from `position` to `side`. Returns [`None`] if nothing was found, the position of the segment if one matched or `Some(None)`
+	/// if the boundary matched.
+	fn find<S: Segment>(&self, segments: &[S], position: usize, side: Side) -> Option<Option<usize>> {
+		let step = |position: usize| {
+			return match side {
+				Side::Before => position.checked_sub(1),
+				Side::After => Some(position.padd(1)).filter(|position| return *position < segments.len()),
+				Side::Around => unreachable!("searching around has to be split"),
+			};
+		};
+
+		if let Side::Around = side {
+			return self
+				.find(segments, position, Side::Before)
+				.or_else(|| return self.find(segments, position, Side::After));
+		}
+
+		let mut position = position;
+
+		while let Some(next) = step(position) {
+			let segment = segments[next];
+
+			if self.pattern.matches(segment) {
+				return Some(Some(next));
+			} else if !self.skip.as_ref().map_or(false, |skip| return skip.matches(segment)) {
+				return None;
+			}
+
+			position = next;
+		}
+
+		if self.pattern.boundary {
+			return Some(None);
+		} else {
+			return None;
+		}
+	}
+}
+
+/// What a [`Rule`] does to its target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "definition", derive(Deserialize), serde(rename_all = "lowercase"))]
+pub enum Action {
+	/// Turn vowel dark.
+	Dark,
+	/// Turn vowel light.
+	Light,
+	/// Turn vowel shading.
+	Shading,
+	/// Turn vowel nasal.
+	Nasal,
+	/// Turn vowel rounding.
+	Rounding,
+	/// Turn radical to its voiceless alternative.
+	Alternative,
+	/// Remove segment.
+	Remove,
+	/// Replace segment with the one found by the first [`Context`], which is removed.
+	Move,
+}
+
+impl Action {
+	/// Get the [`Assimilation`] this is part of, used by [`Derivation`].
+	#[must_use]
+	pub const fn assimilation(self) -> Assimilation {
+		return match self {
+			Self::Dark => Assimilation::Dark,
+			Self::Light => Assimilation::Light,
+			Self::Shading => Assimilation::Shading,
+			Self::Nasal => Assimilation::Nasal,
+			Self::Rounding => Assimilation::Rounding,
+			Self::Alternative => Assimilation::Alternative,
+			Self::Remove | Self::Move => Assimilation::Removal,
+		};
+	}
+}
+
+/// Context-sensitive rewrite: every segment matching the target, that has all [`Context`]s next to it, gets the [`Action`] applied.
+/// All segments are matched before any is changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rule {
+	/// Name.
+	name: String,
+	/// Segments to change.
+	target: Pattern,
+	/// Segments that have to be next to the target.
+	contexts: Vec<Context>,
+	/// Change.
+	action: Action,
+}
+
+impl Rule {
+	/// Build [`Rule`].
+	/// # Errors
+	/// [`Error::RuleMoveInvalid`] if `action` is [`Action::Move`] and the first [`Context`] doesn't match a segment
+	pub fn new(name: &str, target: Pattern, contexts: Vec<Context>, action: Action) -> Result<Self> {
+		if action == Action::Move && contexts.first().map_or(true, |context| return context.pattern.boundary) {
+			return Err(Error::RuleMoveInvalid);
+		}
+
+		return Ok(Self {
+			name: name.to_owned(),
+			target,
+			contexts,
+			action,
+		});
+	}
+
+	/// Get name.
+	#[must_use]
+	pub fn name(&self) -> &str {
+		return &self.name;
+	}
+
+	/// Get segments to change.
+	#[must_use]
+	pub const fn target(&self) -> &Pattern {
+		return &self.target;
+	}
+
+	/// Get segments that have to be next to the target.
+	#[must_use]
+	pub fn contexts(&self) -> &[Context] {
+		return &self.contexts;
+	}
+
+	/// Get change.
+	#[must_use]
+	pub const fn action(&self) -> Action {
+		return self.action;
+	}
+
+	/// Apply to `segments`.
+	pub(crate) fn apply<S: Segment>(&self, segments: &mut ArrayVec<[S; 8]>) {
+		let mut found = ArrayVec::<[_; 8]>::new();
+
+		'targets: for (position, segment) in segments.iter().enumerate() {
+			if !self.target.matches(*segment) {
+				continue;
+			}
+
+			let mut source = None;
+
+			for context in &self.contexts {
+				if let Some(found) = context.find(segments, position, context.side) {
+					source = source.or(found);
+				} else {
+					continue 'targets;
+				}
+			}
+
+			found.push((position, source));
+		}
+
+		for (position, source) in found {
+			if let (Action::Move, Some(source)) = (self.action, source) {
+				let moved = segments[source];
+				segments[position].apply(Action::Move, Some(moved));
+				segments[source].apply(Action::Remove, None);
+			} else {
+				segments[position].apply(self.action, None);
+			}
+		}
+	}
+}
+
+/// Ordered list of [`Rule`]s, see [`Config::set_ipa_rules`].
+///
+/// Rules look like this in TOML:
+/// ```toml
+/// [[rules]]
+/// name = "dark"
+/// # see `Pattern` for the syntax
+/// target = "vowel"
+/// # `side` is "before", "after" or "around", `skip` is optional
+/// contexts = [{ side = "around", match = "consonant dark", skip = "duplicate" }]
+/// # "dark", "light", "shading", "nasal", "rounding", "alternative", "remove" or "move"
+/// action = "dark"
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rules(Vec<Rule>);
+
+impl Rules {
+	/// Build [`Rules`], applied in order.
+	#[must_use]
+	pub const fn new(rules: Vec<Rule>) -> Self {
+		return Self(rules);
+	}
+
+	/// Build [`Rules`] from TOML.
+	/// # Errors
+	/// [`Error::RulesInvalid`] if `string` isn't valid
+	///
+	/// [`Error::FeatureInvalid`] if a [`Pattern`] has an unknown [`Feature`]
+	///
+	/// [`Error::RuleMoveInvalid`] if a [`Rule`] moves a segment without a [`Context`] to move it from
+	#[cfg(feature = "definition")]
+	pub fn from_toml(string: &str) -> Result<Self> {
+		return Self::try_from(toml::from_str::<RulesDefinition>(string).map_err(|_| return Error::RulesInvalid)?);
+	}
+
+	/// Get [`Rule`]s in the order they are applied.
+	#[must_use]
+	pub fn rules(&self) -> &[Rule] {
+		return &self.0;
+	}
+}
+
+impl Default for Rules {
+	/// Build the built-in rules of [`Script::IpaPeter`](crate::Script::IpaPeter).
+	fn default() -> Self {
+		let pattern = |string: &str| return string.parse::<Pattern>().expect("built-in pattern is invalid");
+		let context = |side, string: &str, skip: Option<&str>| return Context::new(side, pattern(string), skip.map(pattern));
+		let rule = |name, target, contexts, action| return Rule::new(name, pattern(target), contexts, action).expect("built-in rule is invalid");
+
+		return Self(vec![
+			// vowels around dark consonants turn dark
+			rule(
+				"dark",
+				"vowel",
+				vec![context(Side::Around, "consonant dark", Some("duplicate"))],
+				Action::Dark,
+			),
+			// vowels around light consonants turn light
+			rule(
+				"light",
+				"vowel",
+				vec![context(Side::Around, "consonant light", Some("duplicate"))],
+				Action::Light,
+			),
+			// vowels after shading consonants turn shading
+			rule(
+				"shading",
+				"vowel",
+				vec![context(Side::Before, "consonant shading", Some("duplicate"))],
+				Action::Shading,
+			),
+			// consonants around voiceless consonants turn to their alternative
+			rule(
+				"alternative",
+				"consonant alternative",
+				vec![context(Side::Around, "consonant voiceless", None)],
+				Action::Alternative,
+			),
+			// the first consonant is removed if it's removed at the beginning
+			rule(
+				"beginning",
+				"consonant beginning",
+				vec![context(Side::Before, "#", Some("!consonant"))],
+				Action::Remove,
+			),
+			// if the last consonant is removed at the end, everything after it is removed too, except the nasal
+			rule(
+				"end tail",
+				"!consonant !nasal",
+				vec![
+					context(Side::Before, "consonant end", Some("!consonant")),
+					context(Side::After, "#", Some("!consonant")),
+				],
+				Action::Remove,
+			),
+			// the last consonant is removed if it's removed at the end
+			rule(
+				"end",
+				"consonant end",
+				vec![context(Side::After, "#", Some("!consonant"))],
+				Action::Remove,
+			),
+			// the vowel before the removed end moves to the nasal
+			rule("end nasal", "nasal", vec![context(Side::Before, "vowel", Some("removed"))], Action::Move),
+			// vowels in nasal position turn nasal
+			rule("nasal", "nasal", Vec::new(), Action::Nasal),
+			// vowels after rounding consonants turn rounding
+			rule(
+				"rounding",
+				"vowel",
+				vec![context(Side::Before, "consonant rounding", Some("duplicate"))],
+				Action::Rounding,
+			),
+		]);
+	}
+}
+
+/// Segment [`Rule`]s can be applied to.
+pub(crate) trait Segment: Copy {
+	/// Check if segment has a [`Feature`].
+	fn has(self, feature: Feature) -> bool;
+
+	/// Apply an [`Action`], [`Action::Move`] replaces the segment with `source`.
+	fn apply(&mut self, action: Action, source: Option<Self>);
+}
+
+/// Raw rules before validation.
+#[cfg(feature = "definition")]
+#[derive(Deserialize)]
+struct RulesDefinition {
+	/// Rules.
+	rules: Vec<RuleDefinition>,
+}
+
+/// Raw rule before validation.
+#[cfg(feature = "definition")]
+#[derive(Deserialize)]
+struct RuleDefinition {
+	/// Name.
+	name: String,
+	/// Target pattern.
+	target: String,
+	/// Contexts.
+	#[serde(default)]
+	contexts: Vec<ContextDefinition>,
+	/// Action.
+	action: Action,
+}
+
+/// Raw context before validation.
+#[cfg(feature = "definition")]
+#[derive(Deserialize)]
+struct ContextDefinition {
+	/// Where to search.
+	side: Side,
+	/// Pattern to find.
+	#[serde(rename = "match")]
+	pattern: String,
+	/// Pattern to skip.
+	skip: Option<String>,
+}
+
+#[cfg(feature = "definition")]
+impl TryFrom<RulesDefinition> for Rules {
+	type Error = Error;
+
+	fn try_from(definition: RulesDefinition) -> Result<Self> {
+		return definition
+			.rules
+			.into_iter()
+			.map(|rule| {
+				let contexts = rule
+					.contexts
+					.into_iter()
+					.map(|context| {
+						return Ok(Context::new(
+							context.side,
+							context.pattern.parse()?,
+							context.skip.map(|skip| return skip.parse()).transpose()?,
+						));
+					})
+					.collect::<Result<_>>()?;
+
+				return Rule::new(&rule.name, rule.target.parse()?, contexts, rule.action);
+			})
+			.collect::<Result<_>>()
+			.map(Self);
+	}
+}
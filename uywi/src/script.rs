@@ -1,45 +1,101 @@
 //! Script handling.
 
+#[cfg(feature = "definition")]
+mod definition;
 mod ipa_peter;
+mod latin;
 mod uywi_chiffre;
+mod x_sampa;
 
 use crate::*;
 use arrayvec::ArrayString;
+#[cfg(feature = "definition")]
+pub use definition::ScriptDefinition;
 use ipa_peter::IPA_PETER;
-use std::fmt::{self, Display, Formatter};
+pub(crate) use ipa_peter::{letter_radicals, radical_features, radical_has, vowel_features};
+use latin::LATIN;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
 use uywi_chiffre::UYWI_CHIFFRE;
+use x_sampa::X_SAMPA;
 
-/// Number of possible radicals.
+/// Number of radicals the built-in [`Script`]s know, which is the size of the default radical inventory.
+/// The radical inventory in use can be a subset of them, see [`Config::set_radicals`].
 pub const NUM_OF_RADICALS: usize = 44;
 /// Size of concept buffer in bytes.
-const CONCEPT_BUFFER: usize = 64;
+pub const CONCEPT_BUFFER: usize = 64;
 /// Size of word buffer in bytes.
-const WORD_BUFFER: usize = 64;
+pub const WORD_BUFFER: usize = 64;
 
 /// Display script.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Only built-in scripts can be deserialized.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(into = "String", try_from = "String"))]
 pub enum Script {
 	/// UYWI Chiffre.
 	UywiChiffre,
 	/// IPA (Peter's script)
 	IpaPeter,
+	/// X-SAMPA, [`Script::IpaPeter`] written in ASCII.
+	XSampa,
+	/// Latin romanization, lowercase letters only.
+	Latin,
+	/// User-defined script, see [`ScriptRegistry`] and [`Extension`] for what it has to implement.
+	Custom(&'static dyn Extension),
 }
 
 impl Script {
-	/// Build [`Concept`] radicals from string.
+	/// Build [`Concept`] radicals from string, normalized to NFC first.
 	/// # Errors
 	/// Returns [`Error`] on failing to build [`Concept`] from string.
 	#[allow(clippy::wrong_self_convention)]
 	pub fn from_concept(self, string: &str) -> Result<Concept> {
-		return self.as_ref().from_concept(string);
+		return self.as_ref().from_concept(&lenient::normalize(string));
 	}
 
-	/// Build [`Concept`] or [`Word`] from string.
+	/// Build [`Concept`] or [`Word`] from string, normalized to NFC first.
+	/// If the string can denote more then one [`Concept`] or [`Word`], the first one is returned and [`Parsed::is_ambiguous`] is set.
 	/// # Errors
 	/// Returns [`Error`] on failing to build [`Concept`] or [`Word`] from string.
 	#[allow(clippy::wrong_self_convention)]
-	pub fn from_str(self, string: &str) -> Result<ConceptOrWord> {
-		return self.as_ref().from_str(string);
+	pub fn from_str(self, string: &str) -> Result<Parsed> {
+		return self.as_ref().from_str(&lenient::normalize(string));
+	}
+
+	/// Build [`Concept`] or [`Word`] from string like [`Script::from_str`], but read look-alikes from [`Orthography::confusables`] as the
+	/// characters they are confused with, e.g. `i` as `ı` in UYWI Chiffre. Look-alikes are only replaced if the string can't be read otherwise,
+	/// as few as possible, and are reported in [`Lenient::substitutions`].
+	/// # Errors
+	/// Returns [`Error`] from [`Script::from_str`] if the string can't be read, even with look-alikes replaced.
+	#[allow(clippy::wrong_self_convention)]
+	pub fn from_str_lenient(self, string: &str) -> Result<Lenient> {
+		return lenient::from_str(self, string);
+	}
+
+	/// Build all [`Concept`]s and [`Word`]s the string can denote, normalized to NFC first.
+	/// # Errors
+	/// Returns [`Error`] on failing to build any [`Concept`] or [`Word`] from string.
+	pub fn parse_all(self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		return self.as_ref().parse_all(&lenient::normalize(string));
+	}
+
+	/// Find up to `limit` [`Concept`]s and [`Word`]s close to a string that couldn't be read, ranked by edit distance in letters,
+	/// together with what is wrong with it. Strings two edits away are only searched if one edit doesn't find `limit` suggestions.
+	#[must_use]
+	pub fn suggest(self, string: &str, limit: usize) -> Suggestions {
+		return suggestion::suggest(self, string, limit);
+	}
+
+	/// Complete a partially typed string to [`Concept`]s and [`Word`]s of `length`, normalized to NFC first.
+	/// Returns the graphemes that can follow it and up to `limit` completions, fewest graphemes left to type first.
+	/// Scripts with [`Lettering::independent_letters`] and the IPA scripts, whose letters follow the installed [`Rules`], are searched letter by
+	/// letter, all others have to build every word of `length`.
+	#[must_use]
+	pub fn complete(self, prefix: &str, length: Length, limit: usize) -> Completions {
+		return completion::complete(self, prefix, length, limit);
 	}
 }
 
@@ -50,16 +106,16 @@ impl Default for Script {
 	}
 }
 
+impl PartialEq for Script {
+	fn eq(&self, other: &Self) -> bool {
+		// scripts are identified by their name, see `ScriptRegistry`
+		return self.name() == other.name();
+	}
+}
+
 impl Display for Script {
 	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
-		return write!(
-			formatter,
-			"{}",
-			match self {
-				Self::UywiChiffre => "Uywi Chiffre",
-				Self::IpaPeter => "IPA (Peter's script)",
-			}
-		);
+		return write!(formatter, "{}", self.name());
 	}
 }
 
@@ -69,21 +125,24 @@ impl AsRef<dyn Extension> for Script {
 		return match self {
 			Self::UywiChiffre => &UYWI_CHIFFRE,
 			Self::IpaPeter => &IPA_PETER,
+			Self::XSampa => &X_SAMPA,
+			Self::Latin => &LATIN,
+			Self::Custom(extension) => *extension,
 		};
 	}
 }
 
 impl Extension for Script {
-	fn script(&self) -> Script {
-		return self.as_ref().script();
+	fn name(&self) -> &str {
+		return self.as_ref().name();
 	}
 
 	fn from_concept(&self, string: &str) -> Result<Concept> {
 		return self.as_ref().from_concept(string);
 	}
 
-	fn from_str(&self, string: &str) -> Result<ConceptOrWord> {
-		return self.as_ref().from_str(string);
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		return self.as_ref().parse_all(string);
 	}
 
 	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]> {
@@ -93,28 +152,115 @@ impl Extension for Script {
 	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]> {
 		return self.as_ref().word(word);
 	}
+
+	fn phonology(&self) -> Option<&dyn Phonology> {
+		return self.as_ref().phonology();
+	}
+
+	fn orthography(&self) -> Option<&dyn Orthography> {
+		return self.as_ref().orthography();
+	}
+
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return self.as_ref().lettering();
+	}
 }
 
 /// Unify all scripts under a common API.
-pub trait Extension {
-	/// Get [`Script`].
-	fn script(&self) -> Script;
+/// Implement this to add your own scripts, see [`ScriptRegistry`].
+///
+/// A [`Script::Custom`] only has to implement [`Extension::name`], [`Extension::from_concept`], [`Extension::parse_all`],
+/// [`Extension::concept`] and [`Extension::word`]. Everything else is an optional capability in its own trait, [`Phonology`],
+/// [`Orthography`] and [`Lettering`], a script opts into one by implementing it and returning itself from its getter here.
+pub trait Extension: Debug + Send + Sync {
+	/// Get name. Has to be unique, it's used to identify the script.
+	fn name(&self) -> &str;
 
 	/// Build [`Concept`] radicals from string.
 	fn from_concept(&self, string: &str) -> Result<Concept>;
 
 	/// Build [`Concept`] or [`Word`] from string.
-	fn from_str(&self, string: &str) -> Result<ConceptOrWord>;
+	fn from_str(&self, string: &str) -> Result<Parsed> {
+		let mut all = self.parse_all(string)?.into_iter();
+		let concept_or_word = all.next().ok_or(Error::WordInvalid)?;
+
+		return Ok(Parsed {
+			concept_or_word,
+			ambiguous: all.next().is_some(),
+		});
+	}
+
+	/// Build all [`Concept`]s and [`Word`]s the string can denote.
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>>;
 
 	/// Build concept string.
 	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]>;
 
 	/// Build word.
 	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]>;
+
+	/// Get [`Phonology`], scripts that don't apply assimilations return [`None`], which is the default.
+	fn phonology(&self) -> Option<&dyn Phonology> {
+		return None;
+	}
+
+	/// Get [`Orthography`], scripts without look-alikes that are sorted by code point return [`None`], which is the default.
+	fn orthography(&self) -> Option<&dyn Orthography> {
+		return None;
+	}
+
+	/// Get [`Lettering`], scripts that can't tell which letter of the stem template wrote what return [`None`], which is the default.
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return None;
+	}
 }
 
-/// Represents a [`Concept`] or [`Word`], return type from [`from_word`].
+/// Capability of scripts that apply assimilations, see [`Extension::phonology`].
+pub trait Phonology {
+	/// Build word step by step, see [`Word::derivation`].
+	fn derivation(&self, word: Word) -> Derivation;
+
+	/// Split word into syllables, see [`Word::syllables`].
+	fn syllables(&self, word: Word) -> Vec<Syllable>;
+}
+
+/// Capability of scripts with their own alphabetical order or look-alikes, see [`Extension::orthography`].
+pub trait Orthography {
+	/// Get look-alikes together with what they are read as by [`Script::from_str_lenient`].
+	/// Scripts without look-alikes return an empty list, which is the default.
+	fn confusables(&self) -> &'static [(&'static str, &'static str)] {
+		return &[];
+	}
+
+	/// Get letters in alphabetical order, used by [`Collation`]. Letters can span more then one character, the longest one is used.
+	/// Scripts that are sorted by code point return an empty list, which is the default.
+	fn alphabet(&self) -> &'static [&'static str] {
+		return &[];
+	}
+}
+
+/// Capability of scripts that can tell what is written for every letter of the stem template, see [`Extension::lettering`].
+/// Used by [`Query`], [`Script::complete`] and [`Script::suggest`].
+pub trait Lettering {
+	/// Split word into what is written for every letter of its stem template, letters that aren't written are empty.
+	fn letters(&self, word: Word) -> Vec<String>;
+
+	/// Get radicals every [`Concept`] has to contain for one of its strings to contain `string`, used by [`Query`] to skip concepts.
+	/// Scripts that can't tell return an empty list, which is the default.
+	fn required_radicals(&self, _string: &str) -> Vec<Radical> {
+		return Vec::new();
+	}
+
+	/// Check if every letter of [`Lettering::letters`] and every radical of a [`Concept`] is written the same, no matter its neighbours.
+	/// Lets [`Script::complete`] skip radicals that don't fit without building every word. Defaults to `false`.
+	fn independent_letters(&self) -> bool {
+		return false;
+	}
+}
+
+/// Represents a [`Concept`] or [`Word`], return type from [`Script::parse_all`].
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum ConceptOrWord {
 	/// A [`Concept`].
 	Concept(Concept),
@@ -131,3 +277,48 @@ impl ConceptOrWord {
 		};
 	}
 }
+
+/// Return type from [`Script::from_str`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Parsed {
+	/// First [`Concept`] or [`Word`] found.
+	concept_or_word: ConceptOrWord,
+	/// If more then one [`Concept`] or [`Word`] was found.
+	ambiguous: bool,
+}
+
+impl Parsed {
+	/// Get [`ConceptOrWord`].
+	#[must_use]
+	pub const fn concept_or_word(self) -> ConceptOrWord {
+		return self.concept_or_word;
+	}
+
+	/// Get if the string could denote more then one [`Concept`] or [`Word`], use [`Script::parse_all`] to get all of them.
+	#[must_use]
+	pub const fn is_ambiguous(self) -> bool {
+		return self.ambiguous;
+	}
+
+	/// Get string from [`Concept`] or [`Word`].
+	#[must_use]
+	pub fn to_string(self, script: Script) -> String {
+		return self.concept_or_word.to_string(script);
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<Script> for String {
+	fn from(script: Script) -> Self {
+		return script.name().to_owned();
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<String> for Script {
+	type Error = Error;
+
+	fn try_from(name: String) -> Result<Self> {
+		return ScriptRegistry::new().get(&name).ok_or(Error::ScriptNameUnknown);
+	}
+}
@@ -0,0 +1,440 @@
+//! Scripts loaded from definitions.
+
+use super::*;
+use crate::*;
+use arrayvec::{ArrayString, ArrayVec};
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Script built from a definition instead of being built-in.
+/// Register it with [`ScriptRegistry::register`] to use it like any other [`Script`].
+///
+/// Definitions look like this in TOML:
+/// ```toml
+/// name = "My script"
+/// # exactly `NUM_OF_RADICALS` radicals, ordered like the radicals of the built-in scripts
+/// # a quality mark is optional and only shown in concepts
+/// radicals = ["?", { glyph = "Y", mark = "ˤ" }, "w", ...]
+///
+/// # glyph of every form vowel, forms themselves come from the current `Config`
+/// [vowels]
+/// a = "o"
+/// e = "o"
+/// i = "ı"
+/// u = "ı"
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScriptDefinition {
+	/// Name.
+	name: String,
+	/// Radicals, ordered like [`Radical::id`].
+	radicals: Vec<RadicalDefinition>,
+	/// Vowels, ordered like [`FormVowel::all`].
+	vowels: Vec<String>,
+}
+
+impl ScriptDefinition {
+	/// Build [`ScriptDefinition`] from TOML.
+	/// # Errors
+	/// [`Error::ScriptDefinitionInvalid`] if `string` isn't a valid definition
+	///
+	/// [`Error::ScriptRadicalsInvalid`] if there aren't exactly [`NUM_OF_RADICALS`] radicals or a radical is empty or too long
+	///
+	/// [`Error::ScriptRadicalDuplicate`] if concepts can't be read back unambiguously because of the radicals
+	///
+	/// [`Error::ScriptFormsInvalid`] if a [`FormVowel`] is missing or unknown or a vowel is empty or too long
+	pub fn from_toml(string: &str) -> Result<Self> {
+		return toml::from_str::<Definition>(string)
+			.map_err(|_| return Error::ScriptDefinitionInvalid)?
+			.validate();
+	}
+
+	/// Build [`ScriptDefinition`] from JSON.
+	/// # Errors
+	/// [`Error::ScriptDefinitionInvalid`] if `string` isn't a valid definition
+	///
+	/// [`Error::ScriptRadicalsInvalid`] if there aren't exactly [`NUM_OF_RADICALS`] radicals or a radical is empty or too long
+	///
+	/// [`Error::ScriptRadicalDuplicate`] if concepts can't be read back unambiguously because of the radicals
+	///
+	/// [`Error::ScriptFormsInvalid`] if a [`FormVowel`] is missing or unknown or a vowel is empty or too long
+	pub fn from_json(string: &str) -> Result<Self> {
+		return serde_json::from_str::<Definition>(string)
+			.map_err(|_| return Error::ScriptDefinitionInvalid)?
+			.validate();
+	}
+
+	/// Check rules every definition has to follow.
+	fn validate(self) -> Result<Self> {
+		// radicals are identified by their position, which the radical inventory can only hold for the built-in ones, see `Config::set_radicals`
+		if self.radicals.len() != NUM_OF_RADICALS {
+			return Err(Error::ScriptRadicalsInvalid);
+		}
+
+		for radical in &self.radicals {
+			// make sure concepts and words fit into their buffers
+			if radical.glyph.is_empty()
+				|| radical.glyph.len() > WORD_BUFFER / structure::MAX_LETTERS
+				|| radical.concept().len() > CONCEPT_BUFFER / MAX_LENGTH
+			{
+				return Err(Error::ScriptRadicalsInvalid);
+			}
+		}
+
+		let concept_radicals: Vec<_> = self.radicals.iter().map(RadicalDefinition::concept).collect();
+
+		if !is_uniquely_decodable(&concept_radicals) {
+			return Err(Error::ScriptRadicalDuplicate);
+		}
+
+		if self.vowels.len() != FormVowel::all().len() {
+			return Err(Error::ScriptFormsInvalid);
+		}
+
+		for vowel in &self.vowels {
+			if vowel.is_empty() || vowel.len() > WORD_BUFFER / structure::MAX_LETTERS {
+				return Err(Error::ScriptFormsInvalid);
+			}
+		}
+
+		return Ok(self);
+	}
+
+	/// Get vowel of a [`Form`].
+	fn vowel(&self, form: Form, vowel: Vowel) -> &str {
+		let vowel = form.get(vowel);
+		let position = FormVowel::all()
+			.iter()
+			.position(|form_vowel| return *form_vowel == vowel)
+			.expect("no form vowel found");
+
+		return &self.vowels[position];
+	}
+
+	/// Find all combinations of radicals that turn into `string` by following `structure` with `form`.
+	fn word_candidates(
+		&self,
+		structure: &[Letter],
+		form: Form,
+		string: &str,
+		radicals: &mut [Option<Radical>; MAX_LENGTH],
+		candidates: &mut Vec<ArrayVec<[Radical; MAX_LENGTH]>>,
+	) {
+		let (letter, structure_left) = if let Some(letter) = structure.split_first() {
+			letter
+		}
+		// the structure is done, it has to have consumed the whole string
+		else {
+			if string.is_empty() {
+				candidates.push(radicals.iter().flatten().copied().collect());
+			}
+
+			return;
+		};
+
+		match letter {
+			Letter::Consonant(radical_index) => {
+				let radical_index = usize::from(*radical_index);
+
+				for (index, id) in Config::current().radicals().iter().enumerate() {
+					let radical = Radical::new(index.pinto());
+					let radical_definition = &self.radicals[usize::from(*id)];
+
+					// radicals can't be used twice in a concept
+					if radicals.contains(&Some(radical)) {
+						continue;
+					}
+
+					if let Some(string_left) = string.strip_prefix(radical_definition.glyph.as_str()) {
+						radicals[radical_index] = Some(radical);
+						self.word_candidates(structure_left, form, string_left, radicals, candidates);
+					}
+				}
+
+				radicals[radical_index] = None;
+			},
+			Letter::DuplicateConsonant(radical_index) => {
+				let radical = radicals[usize::from(*radical_index)].expect("duplicate consonant before consonant");
+
+				if let Some(string_left) = string.strip_prefix(self.radicals[usize::from(radical.id())].glyph.as_str()) {
+					self.word_candidates(structure_left, form, string_left, radicals, candidates);
+				}
+			},
+			Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => {
+				if let Some(string_left) = string.strip_prefix(self.vowel(form, *vowel)) {
+					self.word_candidates(structure_left, form, string_left, radicals, candidates);
+				}
+			},
+		}
+	}
+}
+
+impl Extension for ScriptDefinition {
+	fn name(&self) -> &str {
+		return &self.name;
+	}
+
+	fn from_concept(&self, string: &str) -> Result<Concept> {
+		let concept_radicals: Vec<_> = self.radicals.iter().map(RadicalDefinition::concept).collect();
+		let mut radicals = Vec::new();
+
+		// radicals are uniquely decodable, so there is only one way to split the string
+		if !split(&concept_radicals, string, &mut radicals) {
+			return Err(Error::ConceptRadicalInvalid);
+		}
+
+		let radicals = radicals
+			.into_iter()
+			.map(|id| return Radical::from_id(id.pinto()))
+			.collect::<Result<Vec<_>>>()?;
+
+		return Concept::from_radicals(&radicals);
+	}
+
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		let mut all = Vec::new();
+
+		if let Ok(concept) = self.from_concept(string) {
+			all.push(ConceptOrWord::Concept(concept));
+		}
+
+		for length in &Length::all() {
+			for stem_index in 0..length.stems_per_concept() {
+				let stem_index = stem_index.pinto();
+				let structure = structure::structures(*length, stem_index);
+
+				for (form_index, form) in Config::current().forms(*length).iter().enumerate() {
+					let mut candidates = Vec::new();
+
+					self.word_candidates(&structure, *form, string, &mut [None; MAX_LENGTH], &mut candidates);
+
+					for radicals in candidates {
+						if let Ok(concept) = Concept::from_radicals(&radicals) {
+							let word = ConceptOrWord::Word(Words::new(concept, stem_index).nth(form_index).expect("no word found at form index"));
+
+							if !all.contains(&word) {
+								all.push(word);
+							}
+						}
+					}
+				}
+			}
+		}
+
+		if all.is_empty() {
+			return Err(Error::WordInvalid);
+		} else {
+			return Ok(all);
+		}
+	}
+
+	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]> {
+		let mut string = ArrayString::new();
+
+		for radical in concept.radicals() {
+			string.push_str(&self.radicals[usize::from(radical.id())].concept());
+		}
+
+		return string;
+	}
+
+	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]> {
+		let concept = word.concept();
+		// get concept radicals
+		let concept_radicals = concept.radicals();
+		// get correct form
+		let form = word.form();
+
+		let mut string = ArrayString::new();
+
+		for letter in word.structure() {
+			// print the right letter
+			match letter {
+				Letter::Consonant(radical_index) | Letter::DuplicateConsonant(radical_index) => {
+					let radical_index = usize::from(concept_radicals[usize::from(radical_index)].id());
+
+					string.push_str(&self.radicals[radical_index].glyph)
+				},
+				Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => string.push_str(self.vowel(form, vowel)),
+			};
+		}
+
+		return string;
+	}
+
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return Some(self);
+	}
+}
+
+impl Lettering for ScriptDefinition {
+	fn letters(&self, word: Word) -> Vec<String> {
+		let concept_radicals = word.concept().radicals();
+		let form = word.form();
+
+		return word
+			.structure()
+			.iter()
+			.map(|letter| {
+				return match letter {
+					Letter::Consonant(radical_index) | Letter::DuplicateConsonant(radical_index) => self.radicals
+						[usize::from(concept_radicals[usize::from(*radical_index)].id())]
+					.glyph
+					.clone(),
+					Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => self.vowel(form, *vowel).to_owned(),
+				};
+			})
+			.collect();
+	}
+
+	fn independent_letters(&self) -> bool {
+		return true;
+	}
+}
+
+/// Raw definition before validation.
+#[derive(Deserialize)]
+struct Definition {
+	/// Name.
+	name: String,
+	/// Radicals.
+	radicals: Vec<RadicalDefinition>,
+	/// Vowels, keyed by [`FormVowel`].
+	vowels: BTreeMap<String, String>,
+}
+
+impl Definition {
+	/// Turn into [`ScriptDefinition`] and validate it.
+	fn validate(self) -> Result<ScriptDefinition> {
+		for vowel in self.vowels.keys() {
+			vowel.parse::<FormVowel>().map_err(|_| return Error::ScriptFormsInvalid)?;
+		}
+
+		let vowels = FormVowel::all()
+			.iter()
+			.map(|vowel| return self.vowels.get(vowel.as_str()).cloned().ok_or(Error::ScriptFormsInvalid))
+			.collect::<Result<_>>()?;
+
+		return ScriptDefinition {
+			name: self.name,
+			radicals: self.radicals,
+			vowels,
+		}
+		.validate();
+	}
+}
+
+/// Radical of a [`ScriptDefinition`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(from = "RadicalDefinitionRaw")]
+struct RadicalDefinition {
+	/// Glyph used in concepts and words.
+	glyph: String,
+	/// Quality mark, only shown in concepts.
+	mark: String,
+}
+
+impl RadicalDefinition {
+	/// Get radical in concept form.
+	fn concept(&self) -> String {
+		return [self.glyph.as_str(), self.mark.as_str()].concat();
+	}
+}
+
+/// Radicals can be defined as a string or with a quality mark.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RadicalDefinitionRaw {
+	/// Glyph only.
+	Glyph(String),
+	/// Glyph with quality mark.
+	Marked {
+		/// Glyph.
+		glyph: String,
+		/// Quality mark.
+		#[serde(default)]
+		mark: String,
+	},
+}
+
+impl From<RadicalDefinitionRaw> for RadicalDefinition {
+	fn from(raw: RadicalDefinitionRaw) -> Self {
+		return match raw {
+			RadicalDefinitionRaw::Glyph(glyph) => Self { glyph, mark: String::new() },
+			RadicalDefinitionRaw::Marked { glyph, mark } => Self { glyph, mark },
+		};
+	}
+}
+
+/// Split `string` into `codes`, storing their indexes in `found`, no concept has more then [`MAX_LENGTH`] radicals.
+fn split(codes: &[String], string: &str, found: &mut Vec<usize>) -> bool {
+	if string.is_empty() {
+		return true;
+	} else if found.len() == MAX_LENGTH {
+		return false;
+	}
+
+	for (index, code) in codes.iter().enumerate() {
+		if let Some(string_left) = string.strip_prefix(code.as_str()) {
+			found.push(index);
+
+			if split(codes, string_left, found) {
+				return true;
+			}
+
+			found.pop();
+		}
+	}
+
+	return false;
+}
+
+/// Check if any string built from `codes` can be split into them in only one way.
+/// Uses the Sardinas-Patterson algorithm.
+fn is_uniquely_decodable(codes: &[String]) -> bool {
+	// codes have to be unique in the first place
+	for (position, code) in codes.iter().enumerate() {
+		if codes[..position].contains(code) {
+			return false;
+		}
+	}
+
+	// all non-empty rests of `right` after removing a prefix from `left`
+	let dangling = |left: &BTreeSet<String>, right: &BTreeSet<String>| {
+		let mut suffixes = BTreeSet::new();
+
+		for prefix in left {
+			for string in right {
+				if let Some(suffix) = string.strip_prefix(prefix.as_str()) {
+					if !suffix.is_empty() {
+						suffixes.insert(suffix.to_owned());
+					}
+				}
+			}
+		}
+
+		return suffixes;
+	};
+
+	let codes: BTreeSet<_> = codes.iter().cloned().collect();
+	let mut suffixes = dangling(&codes, &codes);
+	let mut suffixes_seen = BTreeSet::new();
+
+	while !suffixes.is_empty() {
+		// a dangling suffix that is a code itself means a string can be split in two ways
+		if !suffixes.is_disjoint(&codes) {
+			return false;
+		}
+
+		// if we were here before we will never find an ambiguity
+		if !suffixes_seen.insert(suffixes.clone()) {
+			return true;
+		}
+
+		let mut suffixes_next = dangling(&codes, &suffixes);
+		suffixes_next.append(&mut dangling(&suffixes, &codes));
+		suffixes = suffixes_next;
+	}
+
+	return true;
+}
@@ -1,8 +1,9 @@
 //! IPA (Peter's script)
 
 use super::*;
-use crate::*;
+use crate::{rules::Segment, syllable::Unit, *};
 use arrayvec::{ArrayString, ArrayVec};
+use std::{cmp::Reverse, sync::OnceLock};
 
 /// Script instantiation. Used to return from enum without [`Box`].
 pub(super) const IPA_PETER: IpaPeter = IpaPeter {};
@@ -12,23 +13,85 @@ pub(super) const IPA_PETER: IpaPeter = IpaPeter {};
 pub(super) struct IpaPeter {}
 
 impl Extension for IpaPeter {
-	fn script(&self) -> Script {
-		return Script::IpaPeter;
+	fn name(&self) -> &str {
+		return "IPA (Peter's script)";
 	}
 
-	fn from_concept(&self, _string: &str) -> Result<Concept> {
-		unimplemented!("build concept for ipa peter is unimplemented")
+	fn from_concept(&self, string: &str) -> Result<Concept> {
+		// store found radicals
+		let mut radicals = ArrayVec::<[_; MAX_LENGTH]>::new();
+		// part of the string that is left to parse
+		let mut string_left = string;
+
+		while !string_left.is_empty() {
+			// radicals can span multiple graphemes, so find the longest radical the string starts with
+			let (radical_index, radical) = reading()
+				.concept
+				.iter()
+				.enumerate()
+				.filter(|(_, radical)| return string_left.starts_with(radical.as_str()))
+				.max_by_key(|(_, radical)| return radical.len())
+				.ok_or(Error::ConceptRadicalInvalid)?;
+
+			radicals
+				.try_push(Radical::from_id(radical_index.pinto())?)
+				.map_err(|_| return Error::LengthInvalid)?;
+
+			string_left = &string_left[radical.len()..];
+		}
+
+		return Concept::from_radicals(&radicals);
 	}
 
-	fn from_str(&self, _string: &str) -> Result<ConceptOrWord> {
-		unimplemented!("build word for ipa peter is unimplemented")
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		let mut all = Vec::new();
+
+		if let Ok(concept) = self.from_concept(string) {
+			all.push(ConceptOrWord::Concept(concept));
+		}
+
+		// every letter is read once, words are then matched letter by letter against the stem templates, only what fits is built again
+		if let Some(symbols) = symbols(string) {
+			let config = Config::current();
+
+			for length in &Length::all() {
+				for (stem_index, template) in config.templates(*length).iter().enumerate() {
+					let structure = template.letters();
+					let changes = &reading().templates[length.position()][stem_index];
+					let mut alignments = Vec::new();
+
+					align(structure, changes, &symbols, 0, &mut Default::default(), &mut alignments);
+
+					for alignment in &alignments {
+						for concept in concepts(*length, alignment) {
+							for (form_index, form) in config.forms(*length).iter().enumerate() {
+								// assimilations can't be reversed unambiguously, so every candidate that fits is checked by applying them again
+								if alignment.fits(*form) && builds(structure, concept, *form, &symbols) {
+									let word = ConceptOrWord::Word(Word::from_concept(concept, stem_index.pinto(), form_index.pinto())?);
+
+									if !all.contains(&word) {
+										all.push(word);
+									}
+								}
+							}
+						}
+					}
+				}
+			}
+		}
+
+		if all.is_empty() {
+			return Err(Error::WordInvalid);
+		} else {
+			return Ok(all);
+		}
 	}
 
 	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]> {
 		let mut string = ArrayString::new();
 
 		for radical in concept.radicals() {
-			let radical = script_radicals()[usize::from(radical.index())];
+			let radical = script_radicals()[usize::from(radical.id())];
 			string.push_str(&radical.as_str(true, false));
 		}
 
@@ -36,335 +99,765 @@ impl Extension for IpaPeter {
 	}
 
 	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]> {
+		let mut string = ArrayString::new();
+
+		for segment in segments(word) {
+			string.push_str(&segment.letter.as_str());
+		}
+
+		return string;
+	}
+
+	fn phonology(&self) -> Option<&dyn Phonology> {
+		return Some(self);
+	}
+
+	fn orthography(&self) -> Option<&dyn Orthography> {
+		return Some(self);
+	}
+
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return Some(self);
+	}
+}
+
+impl Phonology for IpaPeter {
+	fn derivation(&self, word: Word) -> Derivation {
 		let concept = word.concept();
-		// get correct structure
 		let structure = structure::structures(concept.length(), word.stem_index());
-		// save ipa specfici structure
 		let mut ipa_structure = ArrayVec::<[_; 8]>::new();
+		let segments = |ipa_structure: &ArrayVec<[IpaSegment; 8]>| {
+			return ipa_structure
+				.iter()
+				.map(|segment| return segment.letter.as_str().to_string())
+				.collect::<Vec<_>>();
+		};
 
-		word_base(&structure, concept, word.form_index(), &mut ipa_structure);
-		assimilation_1(&mut ipa_structure);
-		assimilation_2(&mut ipa_structure);
-		assimilation_3(&mut ipa_structure);
-		assimilation_4(&mut ipa_structure);
-		assimilation_5(&structure, &mut ipa_structure);
-		assimilation_6(&structure, &mut ipa_structure);
-		assimilation_7(&mut ipa_structure);
-
-		// build word
-		let mut word = ArrayString::new();
-
-		for letter_ipa in ipa_structure {
-			match letter_ipa {
-				IpaLetter::Radical(radical, alternative) => word.push_str(&radical.as_str(false, alternative)),
-				IpaLetter::Vowel(vowel) => word.push_str(vowel.as_str()),
-				IpaLetter::Duplicate => word.push_str("ː"),
-				IpaLetter::Removed => (),
-			}
+		word_base(&structure, concept, word.form(), &mut ipa_structure);
+
+		let base = segments(&ipa_structure);
+		let mut steps = Vec::new();
+
+		for rule in Config::current().ipa_rules().rules() {
+			let before = ipa_structure.clone();
+			rule.apply(&mut ipa_structure);
+
+			let changes = before
+				.iter()
+				.zip(&ipa_structure)
+				.enumerate()
+				.filter(|(_, (before, after))| return before.letter != after.letter)
+				.map(|(position, (before, after))| {
+					return Change::new(position, before.letter.as_str().to_string(), after.letter.as_str().to_string());
+				})
+				.collect();
+
+			steps.push(Step::new(rule.name(), rule.action().assimilation(), segments(&ipa_structure), changes));
+		}
+
+		let vowel_features = ipa_structure
+			.iter()
+			.map(|segment| {
+				return match segment.letter {
+					IpaLetter::Vowel(vowel) => Some(vowel.features()),
+					IpaLetter::Radical(..) | IpaLetter::Duplicate | IpaLetter::Removed => None,
+				};
+			})
+			.collect();
+
+		return Derivation::new(base, steps, vowel_features);
+	}
+
+	fn syllables(&self, word: Word) -> Vec<Syllable> {
+		let mut units = ArrayVec::<[_; 8]>::new();
+		let mut position = 0;
+
+		for segment in segments(word) {
+			let unit = match segment.letter {
+				IpaLetter::Radical(..) => Unit::Consonant,
+				IpaLetter::Vowel(..) => Unit::Vowel,
+				IpaLetter::Duplicate => Unit::Duplicate,
+				IpaLetter::Removed => continue,
+			};
+			let end = position.padd(segment.letter.as_str().len());
+
+			units.push((position..end, unit));
+			position = end;
 		}
 
-		return word;
+		return syllable::syllabify(&units);
 	}
 }
 
-/// Build base of the word: insert concept radicals, fix stem and form structure and insert neutral vowels.
-fn word_base(structure: &ArrayVec<[Letter; 8]>, concept: Concept, form_index: u8, ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	// get concept radicals
-	let concept_radicals = concept.radicals();
-	// get correct form config
-	let vowels = form_configs(concept.length(), form_index);
+impl Orthography for IpaPeter {
+	fn confusables(&self) -> &'static [(&'static str, &'static str)] {
+		return &[
+			// script `ɡ` and ASCII stand-ins
+			("ɡ", "g"),
+			("'", "ʔ"),
+			("ʼ", "ʔ"),
+			("?", "ʔ"),
+			(":", "ː"),
+			// ligatures, undertie and missing tie bars
+			("ʦ", "t͡s"),
+			("ʨ", "t͡ɕ"),
+			("ʤ", "d͡ʒ"),
+			("\u{35c}", "\u{361}"),
+			("ts", "t͡s"),
+			("tɕ", "t͡ɕ"),
+			("dʒ", "d͡ʒ"),
+			("dʐ", "d͡ʐ"),
+		];
+	}
 
-	for letter_structure in structure {
-		match letter_structure {
-			Letter::Consonant(radical_index) => {
-				let concept_radical_index = usize::from(*radical_index);
-				let radical_index = usize::from(concept_radicals[concept_radical_index].index());
-				let radical = script_radicals()[radical_index];
+	fn alphabet(&self) -> &'static [&'static str] {
+		// every letter follows the latin letter it's closest to, marks come last
+		return &[
+			"a", "ɑ̃", "æ", "b", "c", "ç", "d", "d͡ʐ", "d͡ʒ", "ð", "e", "ɛ̃", "f", "g", "h", "ħ", "i", "ɨ", "j", "ɟ", "k", "kʰ", "l", "ʟ", "m", "n", "ŋ",
+			"ɔ", "ɔ̃", "ø", "œ̃", "p", "r", "ɻ", "ʁ", "s", "ʂ", "ɕ", "ʃ", "t", "tʰ", "t͡ɕ", "t͡s", "θ", "u", "v", "w", "x", "χ", "y", "ɥ", "z", "ʔ", "ʕ",
+			"ː", "ʲ", "ˤ",
+		];
+	}
+}
 
-				ipa_structure.push(IpaLetter::Radical(radical, false));
-			},
-			Letter::Vowel(vowel) | Letter::Nasal(vowel) => {
-				let vowel = vowels.get(*vowel);
-				ipa_structure.push(IpaLetter::Vowel(vowel));
-			},
-			Letter::DuplicateConsonant(..) | Letter::DuplicateVowel(..) => {
-				ipa_structure.push(IpaLetter::Duplicate);
-			},
-		}
+impl Lettering for IpaPeter {
+	fn letters(&self, word: Word) -> Vec<String> {
+		// cheaper then building the whole derivation
+		return segments(word).iter().map(|segment| return segment.letter.as_str().to_string()).collect();
 	}
 }
 
-/// First assimilation: turn all vowels around dark consonants to dark vowels.
-fn assimilation_1(ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	for (position, letter_ipa) in ipa_structure.clone().into_iter().enumerate() {
-		// check if this is a radical
-		if let IpaLetter::Radical(letter, _) = letter_ipa {
-			// check if its quality is dark
-			if letter.is_dark() {
-				// if there is something before it check if it's a vowel
-				if position > 0 {
-					let mut position = position.psub(1);
+/// Build the segments of a word: insert the concept radicals into the stem structure and apply the current [`Rules`].
+fn segments(word: Word) -> ArrayVec<[IpaSegment; 8]> {
+	let concept = word.concept();
+	// get correct structure
+	let structure = structure::structures(concept.length(), word.stem_index());
+	// save ipa specfici structure
+	let mut ipa_structure = ArrayVec::new();
 
-					// if duplicate check the one before it
-					if let IpaLetter::Duplicate = ipa_structure[position] {
-						position = position.psub(1);
-					}
+	word_base(&structure, concept, word.form(), &mut ipa_structure);
 
-					// turn it dark
-					if let IpaLetter::Vowel(vowel) = &mut ipa_structure[position] {
-						vowel.as_dark()
-					}
-				}
+	for rule in Config::current().ipa_rules().rules() {
+		rule.apply(&mut ipa_structure);
+	}
 
-				// if there is something after it check if it's a vowel
-				if position.padd(1) < ipa_structure.len() {
-					let mut position = position.padd(1);
+	return ipa_structure;
+}
 
-					// if duplicate check the one after it
-					if let IpaLetter::Duplicate = ipa_structure[position] {
-						position = position.padd(1);
-					}
+/// Letter of a word as it is written.
+#[derive(Clone, Debug)]
+struct Symbol {
+	/// Letter as it is written.
+	string: ArrayString<[u8; 8]>,
+	/// What kind of letter it is.
+	kind: SymbolKind,
+	/// [`Radical::id`]s of every radical written like this, empty if it isn't a radical.
+	ids: ArrayVec<[u8; 4]>,
+	/// [`FormVowel`]s that turn into this vowel with any assimilation, empty if it isn't a vowel.
+	vowels: ArrayVec<[FormVowel; 4]>,
+}
 
-					if let IpaLetter::Vowel(vowel) = &mut ipa_structure[position] {
-						vowel.as_dark()
-					}
-				}
-			}
-		}
-	}
+/// Kind of [`Symbol`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SymbolKind {
+	/// Radical in its original or alternative form.
+	Radical,
+	/// Vowel.
+	Vowel,
+	/// Duplicate.
+	Duplicate,
 }
 
-/// Second assimilation: turn all vowels around light consonants to light vowels.
-fn assimilation_2(ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	for (position, letter_ipa) in ipa_structure.clone().into_iter().enumerate() {
-		// check if this is a radical
-		if let IpaLetter::Radical(letter, _) = letter_ipa {
-			// check if its quality is light
-			if letter.is_light() {
-				// if there is something before it check if it's a vowel
-				if position > 0 {
-					let mut position = position.psub(1);
+/// Everything needed to read words, see [`reading`].
+#[derive(Debug)]
+struct Reading {
+	/// Every radical as it's written in a concept, ordered by [`Radical::id`].
+	concept: Vec<ArrayString<[u8; 8]>>,
+	/// Every [`Symbol`] that can be written in a word, longest first.
+	symbols: Vec<Symbol>,
+	/// What the rules can do to every letter of every stem template, ordered by [`Length::position`] and stem index.
+	templates: Vec<Vec<Vec<Changes>>>,
+}
 
-					// if duplicate check the one before it
-					if let IpaLetter::Duplicate = ipa_structure[position] {
-						position = position.psub(1);
-					}
+/// What the installed [`Rules`] can do to a letter of a stem template, see [`Reading::templates`].
+#[derive(Clone, Debug, Default)]
+struct Changes {
+	/// If the letter can be missing.
+	removed: bool,
+	/// [`Radical::id`]s of the radicals that can be missing or replaced, only used for [`Letter::Consonant`]s.
+	radicals: Vec<u8>,
+	/// [`SymbolKind`]s that can be moved into the letter.
+	moved: ArrayVec<[SymbolKind; 3]>,
+	/// Positions of the letters that decide what is written for the letter, the letter itself included.
+	depends: ArrayVec<[usize; 8]>,
+}
 
-					// turn it light
-					if let IpaLetter::Vowel(vowel) = &mut ipa_structure[position] {
-						vowel.as_light()
-					}
+impl Changes {
+	/// Find what `rules` can do to every letter of `structure`. Rules are followed in order, but radicals and vowels are unknown, so everything that
+	/// can happen for some concept and form is found.
+	fn new(rules: &[Rule], structure: &[Letter]) -> Vec<Self> {
+		let mut letters: Vec<_> = structure.iter().map(PossibleLetter::new).collect();
+		let mut changes = vec![Self::default(); structure.len()];
+
+		for (position, change) in changes.iter_mut().enumerate() {
+			change.depends.push(position);
+		}
+
+		for rule in rules {
+			// all letters are matched before any is changed
+			let before = letters.clone();
+			let depends: Vec<_> = changes.iter().map(|change| return change.depends.clone()).collect();
+
+			'targets: for (position, letter) in before.iter().enumerate() {
+				// radicals the target can be
+				let ids = if let Letter::Consonant(..) = structure[position] {
+					letter.radicals(rule.target())
+				} else {
+					Vec::new()
+				};
+
+				if letter.matches(rule.target(), None) == Some(false) || (matches!(structure[position], Letter::Consonant(..)) && ids.is_empty()) {
+					continue;
 				}
 
-				// if there is something after it check if it's a vowel
-				if position.padd(1) < ipa_structure.len() {
-					let mut position = position.padd(1);
+				let mut found = Vec::new();
+				// if the rule applies depends on the target and every letter the contexts look at
+				let mut affected = depends[position].clone();
 
-					// if duplicate check the one after it
-					if let IpaLetter::Duplicate = ipa_structure[position] {
-						position = position.padd(1);
+				for context in rule.contexts() {
+					let (positions, boundary, searched) = PossibleLetter::find(&before, context, position, context.side());
+
+					if positions.is_empty() && !boundary {
+						continue 'targets;
 					}
 
-					if let IpaLetter::Vowel(vowel) = &mut ipa_structure[position] {
-						vowel.as_light()
+					for searched in searched {
+						for depend in &depends[searched] {
+							if !affected.contains(depend) {
+								affected.push(*depend);
+							}
+						}
 					}
+
+					found.push(positions);
+				}
+
+				changes[position].add_depends(&affected);
+
+				match rule.action() {
+					Action::Remove => {
+						letters[position].removed = true;
+						changes[position].removed = true;
+						changes[position].add_radicals(&ids);
+					},
+					Action::Move => {
+						changes[position].add_radicals(&ids);
+
+						for source in &found[0] {
+							let source_letter = &before[*source];
+
+							for kind in &source_letter.kinds {
+								letters[position].add_kind(*kind);
+								changes[position].add_moved(*kind);
+							}
+
+							letters[position].moved = true;
+							letters[position].removed |= source_letter.removed;
+							changes[position].removed |= source_letter.removed;
+
+							// the letter that is moved is missing where it was
+							letters[*source].removed = true;
+							changes[*source].removed = true;
+							changes[*source].add_depends(&affected);
+
+							if let Letter::Consonant(..) = structure[*source] {
+								changes[*source].add_radicals(&source_letter.radicals(rule.contexts()[0].pattern()));
+							}
+						}
+					},
+					Action::Dark | Action::Light | Action::Shading | Action::Nasal | Action::Rounding | Action::Alternative => (),
 				}
 			}
 		}
+
+		return changes;
 	}
-}
 
-/// Third assimilation: turn all vowels after shading consonants to shading vowels.
-fn assimilation_3(ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	for (position, letter_ipa) in ipa_structure.clone().into_iter().enumerate() {
-		// check if this is a radical
-		if let IpaLetter::Radical(letter, _) = letter_ipa {
-			// check if its shading
-			if letter.is_shading() {
-				// if there is something after it check if it's a vowel
-				if position.padd(1) < ipa_structure.len() {
-					let mut position = position.padd(1);
+	/// Add radicals that can be missing or replaced.
+	fn add_radicals(&mut self, ids: &[u8]) {
+		for id in ids {
+			if !self.radicals.contains(id) {
+				self.radicals.push(*id);
+			}
+		}
+	}
 
-					// if duplicate check the one after it
-					if let IpaLetter::Duplicate = ipa_structure[position] {
-						position = position.padd(1);
-					}
+	/// Add a [`SymbolKind`] that can be moved into the letter.
+	fn add_moved(&mut self, kind: SymbolKind) {
+		if !self.moved.contains(&kind) {
+			self.moved.push(kind);
+		}
+	}
 
-					if let IpaLetter::Vowel(vowel) = &mut ipa_structure[position] {
-						vowel.as_shading()
-					}
-				}
+	/// Add positions of letters that decide what is written for the letter.
+	fn add_depends(&mut self, positions: &[usize]) {
+		for position in positions {
+			if !self.depends.contains(position) {
+				self.depends.push(*position);
 			}
 		}
 	}
 }
 
-/// Fourth assimilation: turn all consonants around voiceless consonants that are [`Alternative`](Quality3::Alternative) to their alternative form.
-fn assimilation_4(ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	for (position, letter_ipa) in ipa_structure.clone().into_iter().enumerate() {
-		// check if this is a radical
-		if let IpaLetter::Radical(letter, _) = letter_ipa {
-			// check if voiceless
-			if letter.is_voiceless() {
-				// if there is something before it check if it's a consonant
-				if position > 0 {
-					// turn consonant to alternative
-					if let IpaLetter::Radical(letter, alternative) = &mut ipa_structure[position.psub(1)] {
-						if letter.alternative().is_some() {
-							*alternative = true;
-						}
-					}
-				}
+/// What a letter of a stem template can be while [`Rules`] are applied, see [`Changes::new`].
+#[derive(Clone, Debug)]
+struct PossibleLetter {
+	/// [`SymbolKind`]s the letter can be.
+	kinds: ArrayVec<[SymbolKind; 3]>,
+	/// If the letter can be removed.
+	removed: bool,
+	/// If something can be moved into the letter.
+	moved: bool,
+	/// If the letter is in the nasal position.
+	nasal: bool,
+}
 
-				// if there is something after it check if it's a consonant
-				if position.padd(1) < ipa_structure.len() {
-					if let IpaLetter::Radical(letter, alternative) = &mut ipa_structure[position.padd(1)] {
-						if letter.alternative().is_some() {
-							*alternative = true;
-						}
-					}
-				}
-			}
+impl PossibleLetter {
+	/// Build [`PossibleLetter`] before any [`Rule`] is applied.
+	fn new(letter: &Letter) -> Self {
+		let mut kinds = ArrayVec::new();
+		kinds.push(match letter {
+			Letter::Consonant(..) => SymbolKind::Radical,
+			Letter::Vowel(..) | Letter::Nasal(..) => SymbolKind::Vowel,
+			Letter::DuplicateConsonant(..) | Letter::DuplicateVowel(..) => SymbolKind::Duplicate,
+		});
+
+		return Self {
+			kinds,
+			removed: false,
+			moved: false,
+			nasal: matches!(letter, Letter::Nasal(..)),
+		};
+	}
+
+	/// Add a [`SymbolKind`] the letter can be.
+	fn add_kind(&mut self, kind: SymbolKind) {
+		if !self.kinds.contains(&kind) {
+			self.kinds.push(kind);
 		}
 	}
-}
 
-/// Fifth assimilation: if the consonant should be removed at the beginning or end, remove it.
-fn assimilation_5(structure: &ArrayVec<[Letter; 8]>, ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	{
-		// filter only for radicals and get the first one
-		let position = structure
+	/// Check if the letter has `feature`, [`None`] if it depends on the concept or on rules that might not apply.
+	/// Radical features are only known if `radical` is given and it wasn't replaced.
+	fn has(&self, feature: Feature, radical: Option<IpaRadical>) -> Option<bool> {
+		let kind = |kind| {
+			if !self.kinds.contains(&kind) {
+				return Some(false);
+			} else if self.kinds.len() == 1 && !self.removed {
+				return Some(true);
+			} else {
+				return None;
+			}
+		};
+
+		return match feature {
+			Feature::Consonant => kind(SymbolKind::Radical),
+			Feature::Vowel => kind(SymbolKind::Vowel),
+			Feature::Duplicate => kind(SymbolKind::Duplicate),
+			Feature::Removed => Some(false).filter(|_| return !self.removed),
+			Feature::Nasal => Some(self.nasal),
+			Feature::Dark
+			| Feature::Light
+			| Feature::Shading
+			| Feature::Rounding
+			| Feature::Voiceless
+			| Feature::Alternative
+			| Feature::Beginning
+			| Feature::End => match (kind(SymbolKind::Radical), radical) {
+				(Some(false), _) => Some(false),
+				(Some(true), Some(radical)) if !self.moved => Some(
+					IpaSegment {
+						letter: IpaLetter::Radical(radical, false),
+						nasal: self.nasal,
+					}
+					.has(feature),
+				),
+				_ => None,
+			},
+		};
+	}
+
+	/// Get [`Radical::id`]s of the radicals that can match `pattern` in this letter.
+	fn radicals(&self, pattern: &Pattern) -> Vec<u8> {
+		return script_radicals()
 			.iter()
 			.enumerate()
-			.find_map(|(position, letter)| {
-				if let Letter::Consonant(..) = letter {
-					return Some(position);
-				} else {
-					return None;
-				}
-			})
-			.expect("no consonant found");
+			.filter(|(_, radical)| return self.matches(pattern, Some(**radical)) != Some(false))
+			.map(|(id, _)| return id.pinto())
+			.collect();
+	}
 
-		let letter = &mut ipa_structure[position];
+	/// Check if the letter matches `pattern`, [`None`] if it depends on the concept or on rules that might not apply.
+	fn matches(&self, pattern: &Pattern, radical: Option<IpaRadical>) -> Option<bool> {
+		if pattern.is_boundary() {
+			return Some(false);
+		}
 
-		if let IpaLetter::Radical(radical, _) = letter {
-			// remove if consonant is beginning
-			if radical.is_beginning() {
-				*letter = IpaLetter::Removed;
+		let mut matches = Some(true);
+
+		for (feature, present) in pattern.features() {
+			match self.has(*feature, radical) {
+				Some(has) if has != *present => return Some(false),
+				Some(_) => (),
+				None => matches = None,
 			}
-		} else {
-			unreachable!("ipa and letter structure don't match");
 		}
+
+		return matches;
 	}
 
-	{
-		// filter only for radicals and get the last one
-		let position = structure
-			.iter()
-			.enumerate()
-			.filter_map(|(position, letter)| {
-				if let Letter::Consonant(..) = letter {
-					return Some(position);
+	/// Search `context` from `position` to `side` like [`Rule`]s do. Returns every letter that can be the one found, if the boundary can be
+	/// found and every letter that can be looked at.
+	fn find(letters: &[Self], context: &Context, position: usize, side: Side) -> (ArrayVec<[usize; 8]>, bool, ArrayVec<[usize; 8]>) {
+		let steps: ArrayVec<[usize; 8]> = match side {
+			Side::Before => (0..position).rev().collect(),
+			Side::After => (position.padd(1)..letters.len()).collect(),
+			Side::Around => {
+				let (mut found, before, mut searched) = Self::find(letters, context, position, Side::Before);
+				let (after_found, after, after_searched) = Self::find(letters, context, position, Side::After);
+				found.extend(after_found);
+				searched.extend(after_searched);
+
+				return (found, before || after, searched);
+			},
+		};
+		let mut found = ArrayVec::new();
+		let mut searched = ArrayVec::new();
+
+		for next in steps {
+			let letter = &letters[next];
+			searched.push(next);
+
+			match letter.matches(context.pattern(), None) {
+				Some(true) => {
+					found.push(next);
+					return (found, false, searched);
+				},
+				Some(false) => (),
+				None => found.push(next),
+			}
+
+			if context.skip().map_or(Some(false), |skip| return letter.matches(skip, None)) == Some(false) {
+				return (found, false, searched);
+			}
+		}
+
+		return (found, context.pattern().is_boundary(), searched);
+	}
+}
+
+/// Everything needed to read words, built from the installed [`Rules`] and stem templates when it's first used, which can't change afterwards.
+static READING: OnceLock<Reading> = OnceLock::new();
+
+/// Get everything needed to read words.
+fn reading() -> &'static Reading {
+	return READING.get_or_init(|| {
+		let config = Config::current();
+		let rules = config.ipa_rules().rules();
+		let actions: Vec<_> = rules.iter().map(Rule::action).collect();
+		let mut symbols: Vec<Symbol> = Vec::new();
+
+		for (id, radical) in script_radicals().iter().enumerate() {
+			for string in radical.forms(actions.contains(&Action::Alternative)) {
+				// different radicals can be written the same way
+				if let Some(symbol) = symbols.iter_mut().find(|symbol| return symbol.string == string) {
+					symbol.ids.push(id.pinto());
 				} else {
-					return None;
+					let mut ids = ArrayVec::new();
+					ids.push(id.pinto());
+					symbols.push(Symbol {
+						string,
+						kind: SymbolKind::Radical,
+						ids,
+						vowels: ArrayVec::new(),
+					});
 				}
-			})
-			.last()
-			.expect("no consonant found");
-
-		let letter = &mut ipa_structure[position];
-
-		if let IpaLetter::Radical(radical, _) = letter {
-			// remove if consonant is end
-			if radical.is_end() {
-				*letter = IpaLetter::Removed;
-
-				// remove all letters afterwards except the nasal
-				for letter in ipa_structure
-					.iter_mut()
-					.zip(structure)
-					.skip(position)
-					.filter_map(|(letter_ipa, letter_structure)| {
-						if let Letter::Nasal(..) = letter_structure {
-							return None;
-						} else {
-							return Some(letter_ipa);
-						}
-					}) {
-					*letter = IpaLetter::Removed;
-				}
-
-				let mut nasal_found = None;
+			}
+		}
 
-				// if the last letter before the nasal is a vowel, remove it
-				for (letter_ipa, letter_structure) in ipa_structure.iter_mut().zip(structure).rev() {
-					// if we find the nasal, save that we found it
-					if let Letter::Nasal(..) = letter_structure {
-						nasal_found = Some(letter_ipa);
-						continue;
-					}
+		for vowel in &IpaVowel::all() {
+			let string = ArrayString::from(vowel.as_str()).expect("failed to turn vowel to string");
+			let vowels: ArrayVec<[_; 4]> = FormVowel::all()
+				.iter()
+				.copied()
+				.filter(|form_vowel| return IpaVowel::from_form(*form_vowel).assimilated(&actions).contains(vowel))
+				.collect();
+
+			// the rules never write this vowel
+			if vowels.is_empty() {
+				continue;
+			}
 
-					match letter_ipa {
-						// if we found a vowel
-						IpaLetter::Vowel(..) => {
-							// check if we already found the nasal
-							if let Some(nasal_found) = nasal_found {
-								// assign found vowel to nasal
-								*nasal_found = *letter_ipa;
-								*letter_ipa = IpaLetter::Removed;
-								break;
-							// otherwise there was no nasal
-							} else {
-								break;
-							}
-						},
-						// if we find anything else than `Removed` we break
-						IpaLetter::Removed => continue,
-						_ => break,
+			// different vowels can be written the same way
+			if let Some(symbol) = symbols.iter_mut().find(|symbol| return symbol.string == string) {
+				for form_vowel in vowels {
+					if !symbol.vowels.contains(&form_vowel) {
+						symbol.vowels.push(form_vowel);
 					}
 				}
+			} else {
+				symbols.push(Symbol {
+					string,
+					kind: SymbolKind::Vowel,
+					ids: ArrayVec::new(),
+					vowels,
+				});
 			}
-		} else {
-			unreachable!("ipa and letter structure don't match");
 		}
+
+		symbols.push(Symbol {
+			string: IpaLetter::Duplicate.as_str(),
+			kind: SymbolKind::Duplicate,
+			ids: ArrayVec::new(),
+			vowels: ArrayVec::new(),
+		});
+
+		// longest first, so the first symbol a string starts with is the longest one
+		symbols.sort_by_key(|symbol| return Reverse(symbol.string.len()));
+
+		return Reading {
+			concept: script_radicals().iter().map(|radical| return radical.as_str(true, false)).collect(),
+			symbols,
+			templates: Length::all()
+				.iter()
+				.map(|length| {
+					return config
+						.templates(*length)
+						.iter()
+						.map(|template| return Changes::new(rules, template.letters()))
+						.collect();
+				})
+				.collect(),
+		};
+	});
+}
+
+/// Get positions of the radicals in the concept that decide what is written for every letter of a stem template, see [`Script::complete`].
+/// Radicals are followed through the installed [`Rules`] like [`Reading::templates`] are.
+pub(crate) fn letter_radicals(length: Length, stem_index: u8) -> Vec<ArrayVec<[u8; MAX_LENGTH]>> {
+	let structure = structure::structures(length, stem_index);
+
+	return reading().templates[length.position()][usize::from(stem_index)]
+		.iter()
+		.map(|changes| {
+			return changes
+				.depends
+				.iter()
+				.filter_map(|position| {
+					return match structure[*position] {
+						Letter::Consonant(index) | Letter::DuplicateConsonant(index) => Some(index),
+						Letter::Vowel(..) | Letter::DuplicateVowel(..) | Letter::Nasal(..) => None,
+					};
+				})
+				.fold(ArrayVec::new(), |mut radicals, index| {
+					if !radicals.contains(&index) {
+						radicals.push(index);
+					}
+
+					return radicals;
+				});
+		})
+		.collect();
+}
+
+/// Read `string` as [`Symbol`]s, always taking the longest letter, [`None`] if a letter isn't part of this script or it's too long to be a word.
+fn symbols(string: &str) -> Option<ArrayVec<[&'static Symbol; 8]>> {
+	let mut symbols = ArrayVec::new();
+	let mut string_left = string;
+
+	while !string_left.is_empty() {
+		let symbol = reading()
+			.symbols
+			.iter()
+			.find(|symbol| return string_left.starts_with(symbol.string.as_str()))?;
+
+		symbols.try_push(symbol).ok()?;
+		string_left = &string_left[symbol.string.len()..];
 	}
+
+	return Some(symbols);
 }
 
-/// Sixth assimilation: turn vowels that should be nasal to nasal.
-fn assimilation_6(structure: &ArrayVec<[Letter; 8]>, ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	for (letter_structure, letter_ipa) in structure.iter().zip(ipa_structure) {
-		// check if this is a nasal
-		if let Letter::Nasal(..) = letter_structure {
-			if let IpaLetter::Vowel(vowel) = letter_ipa {
-				vowel.as_nasal();
-			} else {
-				unreachable!("ipa and letter structure don't match");
-			}
-		}
+/// Stem template matched against [`Symbol`]s.
+#[derive(Clone, Debug, Default)]
+struct Alignment {
+	/// Radicals each [`Letter::Consonant`] can be, by their [`Radical::id`], [`None`] until the letter is matched.
+	radicals: [Option<Vec<u8>>; MAX_LENGTH],
+	/// Every [`Letter::Vowel`] that is written together with its [`Symbol`].
+	vowels: ArrayVec<[(Vowel, &'static Symbol); 8]>,
+}
+
+impl Alignment {
+	/// Check if the vowels of `form` can turn into the vowels that are written.
+	fn fits(&self, form: Form) -> bool {
+		return self.vowels.iter().all(|(vowel, symbol)| return symbol.vowels.contains(&form.get(*vowel)));
 	}
 }
 
-/// Seventh assimilation: turn all vowels after rounding consonants to rounding vowels.
-fn assimilation_7(ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
-	for (position, letter_ipa) in ipa_structure.clone().into_iter().enumerate() {
-		// check if this is a radical
-		if let IpaLetter::Radical(letter, _) = letter_ipa {
-			// check if its rounding
-			if letter.is_rounding() {
-				// if there is something after it check if it's a vowel
-				if position.padd(1) < ipa_structure.len() {
-					let mut position = position.padd(1);
+/// Match `structure` letter by letter against `symbols` and collect the radicals each consonant can be.
+/// Letters can be missing or replaced where `changes` allow it, everything else is checked by building the word again.
+fn align(
+	structure: &[Letter],
+	changes: &[Changes],
+	symbols: &[&'static Symbol],
+	position: usize,
+	alignment: &mut Alignment,
+	alignments: &mut Vec<Alignment>,
+) {
+	// every letter writes one symbol at most
+	if symbols.len() > structure.len().psub(position) {
+		return;
+	}
+
+	let (letter_structure, letter_changes) = if let (Some(letter_structure), Some(letter_changes)) = (structure.get(position), changes.get(position))
+	{
+		(letter_structure, letter_changes)
+	}
+	// the structure is done, it has to have used every symbol
+	else {
+		if symbols.is_empty() {
+			alignments.push(alignment.clone());
+		}
 
-					// if duplicate check the one after it
-					if let IpaLetter::Duplicate = ipa_structure[position] {
-						position = position.padd(1);
-					}
+		return;
+	};
 
-					if let IpaLetter::Vowel(vowel) = &mut ipa_structure[position] {
-						vowel.as_rounding()
+	let next_position = position.padd(1);
+	let symbol = symbols.first();
+	let moved = symbol.filter(|symbol| return letter_changes.moved.contains(&symbol.kind));
+
+	match letter_structure {
+		Letter::Consonant(radical_index) => {
+			let radical_index = usize::from(*radical_index);
+
+			// radical in its original or alternative form
+			if let Some(symbol) = symbol.filter(|symbol| return symbol.kind == SymbolKind::Radical) {
+				alignment.radicals[radical_index] = Some(symbol.ids.to_vec());
+				align(structure, changes, &symbols[1..], next_position, alignment, alignments);
+			}
+
+			if !letter_changes.radicals.is_empty() {
+				alignment.radicals[radical_index] = Some(letter_changes.radicals.clone());
+
+				// something was moved into the radical
+				if moved.is_some() {
+					align(structure, changes, &symbols[1..], next_position, alignment, alignments);
+				}
+
+				// radical was removed
+				if letter_changes.removed {
+					align(structure, changes, symbols, next_position, alignment, alignments);
+				}
+			}
+
+			alignment.radicals[radical_index] = None;
+		},
+		Letter::Vowel(vowel) | Letter::Nasal(vowel) => {
+			if let Some(symbol) = symbol {
+				// a vowel that can be moved here might not be this one
+				if !letter_changes.moved.is_empty() {
+					if symbol.kind == SymbolKind::Vowel || moved.is_some() {
+						align(structure, changes, &symbols[1..], next_position, alignment, alignments);
 					}
+				} else if symbol.kind == SymbolKind::Vowel {
+					alignment.vowels.push((*vowel, symbol));
+					align(structure, changes, &symbols[1..], next_position, alignment, alignments);
+					alignment.vowels.pop();
 				}
 			}
+
+			// vowel was removed
+			if letter_changes.removed {
+				align(structure, changes, symbols, next_position, alignment, alignments);
+			}
+		},
+		Letter::DuplicateConsonant(..) | Letter::DuplicateVowel(..) => {
+			if symbol.map_or(false, |symbol| return symbol.kind == SymbolKind::Duplicate) || moved.is_some() {
+				align(structure, changes, &symbols[1..], next_position, alignment, alignments);
+			}
+
+			// duplicate was removed
+			if letter_changes.removed {
+				align(structure, changes, symbols, next_position, alignment, alignments);
+			}
+		},
+	}
+}
+
+/// Get every [`Concept`] of `length` that fits `alignment`.
+fn concepts(length: Length, alignment: &Alignment) -> Vec<Concept> {
+	let mut concepts = vec![ArrayVec::<[Radical; MAX_LENGTH]>::new()];
+
+	for ids in &alignment.radicals[..length.as_int()] {
+		let ids = if let Some(ids) = ids { ids } else { return Vec::new() };
+
+		concepts = concepts
+			.into_iter()
+			.flat_map(|radicals| {
+				return ids.iter().filter_map(move |id| {
+					let radical = Radical::from_id(*id).ok()?;
+					let mut radicals = radicals.clone();
+					radicals.push(radical);
+
+					return Some(radicals);
+				});
+			})
+			.collect();
+	}
+
+	return concepts
+		.iter()
+		.filter_map(|radicals| return Concept::from_radicals(radicals).ok())
+		.collect();
+}
+
+/// Check if building the word from `structure`, `concept` and `form` writes `symbols`.
+fn builds(structure: &[Letter], concept: Concept, form: Form, symbols: &[&Symbol]) -> bool {
+	let mut ipa_structure = ArrayVec::new();
+	word_base(structure, concept, form, &mut ipa_structure);
+
+	for rule in Config::current().ipa_rules().rules() {
+		rule.apply(&mut ipa_structure);
+	}
+
+	return ipa_structure
+		.iter()
+		.map(|segment| return segment.letter.as_str())
+		.filter(|letter| return !letter.is_empty())
+		.eq(symbols.iter().map(|symbol| return symbol.string));
+}
+
+/// Build base of the word: insert concept radicals, fix stem and form structure and insert neutral vowels.
+fn word_base(structure: &[Letter], concept: Concept, form: Form, ipa_structure: &mut ArrayVec<[IpaSegment; 8]>) {
+	// get concept radicals
+	let concept_radicals = concept.radicals();
+
+	for letter_structure in structure {
+		match letter_structure {
+			Letter::Consonant(radical_index) => {
+				let concept_radical_index = usize::from(*radical_index);
+				let radical_index = usize::from(concept_radicals[concept_radical_index].id());
+				let radical = script_radicals()[radical_index];
+
+				ipa_structure.push(IpaSegment::new(IpaLetter::Radical(radical, false), letter_structure));
+			},
+			Letter::Vowel(vowel) | Letter::Nasal(vowel) => {
+				let vowel = IpaVowel::from_form(form.get(*vowel));
+				ipa_structure.push(IpaSegment::new(IpaLetter::Vowel(vowel), letter_structure));
+			},
+			Letter::DuplicateConsonant(..) | Letter::DuplicateVowel(..) => {
+				ipa_structure.push(IpaSegment::new(IpaLetter::Duplicate, letter_structure));
+			},
 		}
 	}
 }
@@ -372,84 +865,117 @@ fn assimilation_7(ipa_structure: &mut ArrayVec<[IpaLetter; 8]>) {
 /// List of radicals with all exceptions.
 #[allow(clippy::enum_glob_use)]
 const fn script_radicals() -> [IpaRadical; NUM_OF_RADICALS] {
-	use Quality1::*;
-	use Quality2::*;
-	use Quality3::*;
-	use Quality4::*;
+	use Articulation::*;
+	use Coloring::*;
+	use Manner::*;
+	use Place::*;
+	use Removal::*;
+	use Voicing::*;
 
 	#[rustfmt::skip]
 	return [
-		IpaRadical("ʔ",  Neutral, false, None,           None,                   Some(Beginning)),
-		IpaRadical("j",  Dark,    false, None,           None,                   None),
-		IpaRadical("w",  Neutral, false, None,           None,                   None),
-		IpaRadical("h",  Neutral, false, None,           Some(Voiceless),        Some(End)),
-		IpaRadical("ʕ",  Neutral, false, None,           None,                   None),
-		IpaRadical("ħ",  Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("k",  Dark,    false, Some(Shading),  Some(Voiceless),        None),
-		IpaRadical("kʰ", Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("x",  Dark,    false, Some(Shading),  Some(Voiceless),        None),
-		IpaRadical("ç",  Light,   false, None,           Some(Voiceless),        None),
-		IpaRadical("ʁ",  Neutral, false, None,           Some(Alternative("χ")), None),
-		IpaRadical("ɟ",  Neutral, false, None,           Some(Alternative("c")), None),
-		IpaRadical("g",  Dark,    false, Some(Shading),  None,                   None),
-		IpaRadical("g",  Light,   false, None,           None,                   None),
-		IpaRadical("ɥ",  Light,   false, Some(Rounding), None,                   None),
-		IpaRadical("d͡ʐ", Dark,    false, Some(Shading),  None,                   None),
-		IpaRadical("d͡ʒ", Neutral, false, None,           None,                   None),
-		IpaRadical("ʂ",  Dark,    false, Some(Shading),  Some(Voiceless),        None),
-		IpaRadical("ɕ",  Light,   false, None,           Some(Voiceless),        None),
-		IpaRadical("ʃ",  Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("s",  Dark,    false, Some(Shading),  Some(Voiceless),        None),
-		IpaRadical("s",  Light,   true,  None,           Some(Voiceless),        None),
-		IpaRadical("z",  Dark,    false, Some(Shading),  None,                   None),
-		IpaRadical("z",  Light,   true,  None,           None,                   None),
-		IpaRadical("d",  Dark,    false, Some(Shading),  None,                   None),
-		IpaRadical("d",  Light,   true,  None,           None,                   None),
-		IpaRadical("t",  Dark,    false, Some(Shading),  Some(Voiceless),        None),
-		IpaRadical("tʰ", Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("t͡ɕ", Light,   false, None,           Some(Voiceless),        None),
-		IpaRadical("t͡s", Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("θ",  Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("ð",  Neutral, false, None,           None,                   None),
-		IpaRadical("v",  Dark,    false, None,           None,                   None),
-		IpaRadical("f",  Neutral, false, None,           Some(Voiceless),        None),
-		IpaRadical("p",  Neutral, false, Some(Rounding), Some(Voiceless),        None),
-		IpaRadical("b",  Neutral, false, Some(Rounding), None,                   None),
-		IpaRadical("m",  Neutral, false, Some(Rounding), None,                   None),
-		IpaRadical("n",  Neutral, false, None,           None,                   None),
-		IpaRadical("ŋ",  Neutral, false, None,           None,                   None),
-		IpaRadical("ɻ",  Dark,    false, None,			 None,                   None),
-		IpaRadical("r",  Neutral, false, None,           None,                   None),
-		IpaRadical("l",  Light,   true,  None,           None,                   None),
-		IpaRadical("ʟ",  Dark,    false, Some(Shading),  None,                   None),
-		IpaRadical("l",  Neutral, false, None,           None,                   None),
+		radical("ʔ",  Glottal,        Plosive,            None,            Neutral, None,           false, None,      Some(Beginning)),
+		radical("j",  Palatal,        Approximant,        Some(Voiced),    Dark,    None,           false, None,      None),
+		radical("w",  LabialVelar,    Approximant,        Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("h",  Glottal,        Fricative,          Some(Voiceless), Neutral, None,           false, None,      Some(End)),
+		radical("ʕ",  Pharyngeal,     Fricative,          Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("ħ",  Pharyngeal,     Fricative,          Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("k",  Velar,          Plosive,            Some(Voiceless), Dark,    Some(Shading),  false, None,      None),
+		radical("kʰ", Velar,          Plosive,            Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("x",  Velar,          Fricative,          Some(Voiceless), Dark,    Some(Shading),  false, None,      None),
+		radical("ç",  Palatal,        Fricative,          Some(Voiceless), Light,   None,           false, None,      None),
+		radical("ʁ",  Uvular,         Fricative,          Some(Voiced),    Neutral, None,           false, Some("χ"), None),
+		radical("ɟ",  Palatal,        Plosive,            Some(Voiced),    Neutral, None,           false, Some("c"), None),
+		radical("g",  Velar,          Plosive,            Some(Voiced),    Dark,    Some(Shading),  false, None,      None),
+		radical("g",  Velar,          Plosive,            Some(Voiced),    Light,   None,           false, None,      None),
+		radical("ɥ",  LabialPalatal,  Approximant,        Some(Voiced),    Light,   Some(Rounding), false, None,      None),
+		radical("d͡ʐ", Retroflex,      Affricate,          Some(Voiced),    Dark,    Some(Shading),  false, None,      None),
+		radical("d͡ʒ", Postalveolar,   Affricate,          Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("ʂ",  Retroflex,      Fricative,          Some(Voiceless), Dark,    Some(Shading),  false, None,      None),
+		radical("ɕ",  AlveoloPalatal, Fricative,          Some(Voiceless), Light,   None,           false, None,      None),
+		radical("ʃ",  Postalveolar,   Fricative,          Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("s",  Alveolar,       Fricative,          Some(Voiceless), Dark,    Some(Shading),  false, None,      None),
+		radical("s",  Alveolar,       Fricative,          Some(Voiceless), Light,   None,           true,  None,      None),
+		radical("z",  Alveolar,       Fricative,          Some(Voiced),    Dark,    Some(Shading),  false, None,      None),
+		radical("z",  Alveolar,       Fricative,          Some(Voiced),    Light,   None,           true,  None,      None),
+		radical("d",  Alveolar,       Plosive,            Some(Voiced),    Dark,    Some(Shading),  false, None,      None),
+		radical("d",  Alveolar,       Plosive,            Some(Voiced),    Light,   None,           true,  None,      None),
+		radical("t",  Alveolar,       Plosive,            Some(Voiceless), Dark,    Some(Shading),  false, None,      None),
+		radical("tʰ", Alveolar,       Plosive,            Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("t͡ɕ", AlveoloPalatal, Affricate,          Some(Voiceless), Light,   None,           false, None,      None),
+		radical("t͡s", Alveolar,       Affricate,          Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("θ",  Dental,         Fricative,          Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("ð",  Dental,         Fricative,          Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("v",  Labiodental,    Fricative,          Some(Voiced),    Dark,    None,           false, None,      None),
+		radical("f",  Labiodental,    Fricative,          Some(Voiceless), Neutral, None,           false, None,      None),
+		radical("p",  Bilabial,       Plosive,            Some(Voiceless), Neutral, Some(Rounding), false, None,      None),
+		radical("b",  Bilabial,       Plosive,            Some(Voiced),    Neutral, Some(Rounding), false, None,      None),
+		radical("m",  Bilabial,       Nasal,              Some(Voiced),    Neutral, Some(Rounding), false, None,      None),
+		radical("n",  Alveolar,       Nasal,              Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("ŋ",  Velar,          Nasal,              Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("ɻ",  Retroflex,      Approximant,        Some(Voiced),    Dark,    None,           false, None,      None),
+		radical("r",  Alveolar,       Trill,              Some(Voiced),    Neutral, None,           false, None,      None),
+		radical("l",  Alveolar,       LateralApproximant, Some(Voiced),    Light,   None,           true,  None,      None),
+		radical("ʟ",  Velar,          LateralApproximant, Some(Voiced),    Dark,    Some(Shading),  false, None,      None),
+		radical("l",  Alveolar,       LateralApproximant, Some(Voiced),    Neutral, None,           false, None,      None),
 	];
 }
 
-/// List how forms are configured.
-#[allow(clippy::enum_glob_use)]
-fn form_configs(length: Length, form_index: u8) -> Vowels {
-	use IpaVowel::*;
+/// Build [`IpaRadical`], used to keep [`script_radicals`] readable.
+#[allow(clippy::too_many_arguments)]
+const fn radical(
+	glyph: &'static str,
+	place: Place,
+	manner: Manner,
+	voicing: Option<Voicing>,
+	articulation: Articulation,
+	coloring: Option<Coloring>,
+	marked: bool,
+	alternative: Option<&'static str>,
+	removal: Option<Removal>,
+) -> IpaRadical {
+	return IpaRadical {
+		glyph,
+		features: RadicalFeatures::new(place, manner, voicing, articulation, coloring),
+		marked,
+		alternative,
+		removal,
+	};
+}
 
-	let mut configs = ArrayVec::<[_; 4]>::new();
+/// Get [`RadicalFeatures`] of a radical, see [`Radical::features`].
+pub(crate) fn radical_features(id: u8) -> RadicalFeatures {
+	return script_radicals()[usize::from(id)].features;
+}
 
-	match length {
-		Length::L2 => configs.try_extend_from_slice(&[Vowels(NeutralA, NeutralA), Vowels(NeutralI, NeutralI)]),
-		Length::L3 | Length::L4 => configs.try_extend_from_slice(&[
-			Vowels(NeutralA, NeutralE),
-			Vowels(NeutralE, NeutralI),
-			Vowels(NeutralU, NeutralA),
-			Vowels(NeutralU, NeutralI),
-		]),
+/// Check if a radical has a [`Feature`] the way [`Rules`] see it, see [`Query`].
+pub(crate) fn radical_has(id: u8, feature: Feature) -> bool {
+	return IpaSegment {
+		letter: IpaLetter::Radical(script_radicals()[usize::from(id)], false),
+		nasal: false,
 	}
-	.expect("failed to fill form configs");
+	.has(feature);
+}
 
-	return configs[usize::from(form_index)];
+/// Get [`VowelFeatures`] of a [`FormVowel`], see [`FormVowel::features`].
+pub(crate) const fn vowel_features(vowel: FormVowel) -> VowelFeatures {
+	return IpaVowel::from_form(vowel).features();
 }
 
-/// Save radicals with all the exceptions.
-#[derive(Clone, Copy, Debug)]
-struct IpaRadical(&'static str, Quality1, bool, Option<Quality2>, Option<Quality3>, Option<Quality4>);
+/// Radical with everything needed to write it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct IpaRadical {
+	/// Glyph.
+	glyph: &'static str,
+	/// Phonological features.
+	features: RadicalFeatures,
+	/// If the quality mark is always shown, not only in concepts.
+	marked: bool,
+	/// Glyph used next to voiceless radicals.
+	alternative: Option<&'static str>,
+	/// Position in a word where the radical is removed.
+	removal: Option<Removal>,
+}
 
 impl IpaRadical {
 	/// Get radical in string form.
@@ -457,165 +983,59 @@ impl IpaRadical {
 		// start with original or with alternative
 		let mut string = ArrayString::from({
 			if alternative {
-				self.alternative().expect("no alternative found")
+				self.alternative.expect("no alternative found")
 			} else {
-				self.0
+				self.glyph
 			}
 		})
 		.expect("failed to turn radical to string");
 
-		// sow quality if forced or part of the letter
-		if force_quality || self.2 {
-			string.push_str(self.quality().as_str())
+		// show quality if forced or part of the letter
+		if force_quality || self.marked {
+			string.push_str(match self.features.articulation() {
+				Articulation::Neutral => "",
+				Articulation::Dark => "ˤ",
+				Articulation::Light => "ʲ",
+			})
 		}
 
 		return string;
 	}
 
-	/// Get radical's quality.
-	const fn quality(self) -> Quality1 {
-		return self.1;
-	}
-
-	/// Get if radical is dark.
-	fn is_dark(self) -> bool {
-		if let Quality1::Dark = self.1 {
-			return true;
-		} else {
-			return false;
-		}
-	}
+	/// Get every way the radical can be written inside a word, original form first, the alternative only if `alternative` is set.
+	fn forms(self, alternative: bool) -> ArrayVec<[ArrayString<[u8; 8]>; 2]> {
+		let mut forms = ArrayVec::new();
+		forms.push(self.as_str(false, false));
 
-	/// Get if radical is light.
-	fn is_light(self) -> bool {
-		if let Quality1::Light = self.1 {
-			return true;
-		} else {
-			return false;
+		if alternative && self.alternative.is_some() {
+			forms.push(self.as_str(false, true));
 		}
-	}
 
-	/// Get if radical is shading.
-	fn is_shading(self) -> bool {
-		if let Some(Quality2::Shading) = self.3 {
-			return true;
-		} else {
-			return false;
-		}
+		return forms;
 	}
 
-	/// Get if radical is rounding.
-	fn is_rounding(self) -> bool {
-		if let Some(Quality2::Rounding) = self.3 {
-			return true;
-		} else {
-			return false;
-		}
-	}
-
-	/// Get radical's alternative.
-	fn is_voiceless(self) -> bool {
-		if let Some(Quality3::Voiceless) = self.4 {
-			return true;
-		} else {
-			return false;
-		}
-	}
-
-	/// Get radical's alternative.
-	fn alternative(self) -> Option<&'static str> {
-		if let Some(Quality3::Alternative(alternative)) = self.4 {
-			return Some(alternative);
-		} else {
-			return None;
-		}
-	}
-
-	/// Get if radical has a quality that removes it a the beginning of a word.
+	/// Get if radical is removed at the beginning of a word.
 	fn is_beginning(self) -> bool {
-		if let Some(Quality4::Beginning) = self.5 {
-			return true;
-		} else {
-			return false;
-		}
+		return self.removal == Some(Removal::Beginning);
 	}
 
-	/// Get if radical has a quality that removes it a the end of a word.
+	/// Get if radical is removed at the end of a word.
 	fn is_end(self) -> bool {
-		if let Some(Quality4::End) = self.5 {
-			return true;
-		} else {
-			return false;
-		}
+		return self.removal == Some(Removal::End);
 	}
 }
 
-/// Quality of radical.
-#[derive(Clone, Copy, Debug)]
-enum Quality1 {
-	/// Neutral radical.
-	Neutral,
-	/// Dark radical.
-	Dark,
-	/// Light radical.
-	Light,
-}
-
-impl Quality1 {
-	/// Get's `str` form of quality.
-	fn as_str(self) -> &'static str {
-		return match self {
-			Self::Neutral => "",
-			Self::Dark => "ˤ",
-			Self::Light => "ʲ",
-		};
-	}
-}
-
-/// Shading and rounding.
-#[derive(Clone, Copy, Debug)]
-enum Quality2 {
-	/// Shading.
-	Shading,
-	/// Rounding.
-	Rounding,
-}
-
-/// Voiceless and alternatives.
-#[derive(Clone, Copy, Debug)]
-enum Quality3 {
-	/// Voiceless.
-	Voiceless,
-	/// Alternative consonant.
-	Alternative(&'static str),
-}
-
-/// Remove if at beginning or end.
-#[derive(Clone, Copy, Debug)]
-enum Quality4 {
-	/// Remove when its at the beginning.
+/// Position in a word where a radical is removed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Removal {
+	/// Removed at the beginning.
 	Beginning,
-	/// Remove when its at the End.
+	/// Removed at the end.
 	End,
 }
 
-/// Stores vowels.
-/// Purely there to make it easier to extract vowels with [`Vowel`].
-#[derive(Clone, Copy, Debug)]
-struct Vowels(IpaVowel, IpaVowel);
-
-impl Vowels {
-	/// Gets the right vowel with [`Vowel`].
-	fn get(self, vowel: Vowel) -> IpaVowel {
-		return match vowel {
-			Vowel::First => self.0,
-			Vowel::Last => self.1,
-		};
-	}
-}
-
 /// Represents letter.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum IpaLetter {
 	/// Radical.
 	Radical(IpaRadical, bool),
@@ -627,8 +1047,79 @@ enum IpaLetter {
 	Removed,
 }
 
+impl IpaLetter {
+	/// Get in string form, removed letters are empty.
+	fn as_str(self) -> ArrayString<[u8; 8]> {
+		return match self {
+			Self::Radical(radical, alternative) => radical.as_str(false, alternative),
+			Self::Vowel(vowel) => ArrayString::from(vowel.as_str()).expect("failed to turn vowel to string"),
+			Self::Duplicate => ArrayString::from("ː").expect("failed to turn duplicate to string"),
+			Self::Removed => ArrayString::new(),
+		};
+	}
+}
+
+/// Letter together with its position in the stem template, what [`Rule`]s are applied to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct IpaSegment {
+	/// Letter.
+	letter: IpaLetter,
+	/// If this is the nasal position.
+	nasal: bool,
+}
+
+impl IpaSegment {
+	/// Build [`IpaSegment`] at the position of `letter_structure`.
+	fn new(letter: IpaLetter, letter_structure: &Letter) -> Self {
+		return Self {
+			letter,
+			nasal: matches!(letter_structure, Letter::Nasal(..)),
+		};
+	}
+}
+
+impl Segment for IpaSegment {
+	fn has(self, feature: Feature) -> bool {
+		return match (feature, self.letter) {
+			(Feature::Consonant, IpaLetter::Radical(..))
+			| (Feature::Vowel, IpaLetter::Vowel(..))
+			| (Feature::Duplicate, IpaLetter::Duplicate)
+			| (Feature::Removed, IpaLetter::Removed) => true,
+			(Feature::Nasal, _) => self.nasal,
+			(Feature::Dark, IpaLetter::Radical(radical, _)) => radical.features.is_dark(),
+			(Feature::Light, IpaLetter::Radical(radical, _)) => radical.features.is_light(),
+			(Feature::Shading, IpaLetter::Radical(radical, _)) => radical.features.is_shading(),
+			(Feature::Rounding, IpaLetter::Radical(radical, _)) => radical.features.is_rounding(),
+			(Feature::Voiceless, IpaLetter::Radical(radical, _)) => radical.features.is_voiceless(),
+			(Feature::Alternative, IpaLetter::Radical(radical, _)) => radical.alternative.is_some(),
+			(Feature::Beginning, IpaLetter::Radical(radical, _)) => radical.is_beginning(),
+			(Feature::End, IpaLetter::Radical(radical, _)) => radical.is_end(),
+			_ => false,
+		};
+	}
+
+	fn apply(&mut self, action: Action, source: Option<Self>) {
+		match (action, &mut self.letter) {
+			(Action::Dark, IpaLetter::Vowel(vowel)) => vowel.as_dark(),
+			(Action::Light, IpaLetter::Vowel(vowel)) => vowel.as_light(),
+			(Action::Shading, IpaLetter::Vowel(vowel)) => vowel.as_shading(),
+			(Action::Nasal, IpaLetter::Vowel(vowel)) => vowel.as_nasal(),
+			(Action::Rounding, IpaLetter::Vowel(vowel)) => vowel.as_rounding(),
+			(Action::Alternative, IpaLetter::Radical(radical, alternative)) => {
+				if radical.alternative.is_some() {
+					*alternative = true;
+				}
+			},
+			(Action::Remove, letter) => *letter = IpaLetter::Removed,
+			// the position stays the same, only the letter moves
+			(Action::Move, letter) => *letter = source.expect("nothing to move found").letter,
+			_ => (),
+		}
+	}
+}
+
 /// Represents vowels.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum IpaVowel {
 	/// Neutral `a`.
 	NeutralA,
@@ -667,6 +1158,52 @@ enum IpaVowel {
 }
 
 impl IpaVowel {
+	/// All vowels.
+	#[allow(clippy::enum_glob_use)]
+	const fn all() -> [Self; 17] {
+		use IpaVowel::*;
+
+		return [
+			NeutralA, NeutralE, NeutralI, NeutralU, DarkA, DarkU, LightE, LightI, NasalA, NasalE, NasalI, NasalU, ShadingE, ShadingI, RoundingA,
+			RoundingE, RoundingI,
+		];
+	}
+
+	/// Get phonological features.
+	#[rustfmt::skip]
+	const fn features(self) -> VowelFeatures {
+		use Backness::{Back, Central, Front};
+		use Height::{Close, CloseMid, NearOpen, Open, OpenMid};
+
+		let (height, backness, rounded, nasal) = match self {
+			Self::NeutralA | Self::DarkA  => (Open,     Front,   false, false),
+			Self::NeutralE | Self::LightE => (CloseMid, Front,   false, false),
+			Self::NeutralI | Self::LightI => (Close,    Front,   false, false),
+			Self::NeutralU | Self::DarkU  => (Close,    Back,    true,  false),
+			Self::NasalA                  => (Open,     Back,    false, true),
+			Self::NasalE                  => (OpenMid,  Back,    true,  true),
+			Self::NasalI                  => (OpenMid,  Front,   false, true),
+			Self::NasalU                  => (OpenMid,  Front,   true,  true),
+			Self::ShadingE                => (NearOpen, Front,   false, false),
+			Self::ShadingI                => (Close,    Central, false, false),
+			Self::RoundingA               => (OpenMid,  Back,    true,  false),
+			Self::RoundingE               => (CloseMid, Front,   true,  false),
+			Self::RoundingI               => (Close,    Front,   true,  false),
+		};
+
+		return VowelFeatures::new(height, backness, rounded, nasal);
+	}
+
+	/// Get neutral vowel of a [`FormVowel`].
+	const fn from_form(vowel: FormVowel) -> Self {
+		return match vowel {
+			FormVowel::A => Self::NeutralA,
+			FormVowel::E => Self::NeutralE,
+			FormVowel::I => Self::NeutralI,
+			FormVowel::U => Self::NeutralU,
+		};
+	}
+
 	/// Get in `str` form.
 	fn as_str(self) -> &'static str {
 		return match self {
@@ -686,6 +1223,38 @@ impl IpaVowel {
 		};
 	}
 
+	/// Get every vowel this can turn into with any of `actions` in any order, including itself.
+	fn assimilated(self, actions: &[Action]) -> ArrayVec<[Self; 17]> {
+		let mut vowels = ArrayVec::<[Self; 17]>::new();
+		vowels.push(self);
+		let mut position = 0;
+
+		while let Some(vowel) = vowels.get(position).copied() {
+			// removing and moving doesn't turn a vowel into another one
+			for action in actions.iter().filter(|action| return !matches!(action, Action::Remove | Action::Move)) {
+				let mut assimilated = IpaSegment {
+					letter: IpaLetter::Vowel(vowel),
+					nasal: false,
+				};
+				assimilated.apply(*action, None);
+
+				let assimilated = if let IpaLetter::Vowel(assimilated) = assimilated.letter {
+					assimilated
+				} else {
+					continue;
+				};
+
+				if !vowels.contains(&assimilated) {
+					vowels.push(assimilated);
+				}
+			}
+
+			position = position.padd(1);
+		}
+
+		return vowels;
+	}
+
 	/// Turn vowel dark.
 	fn as_dark(&mut self) {
 		match self {
@@ -0,0 +1,279 @@
+//! Latin romanization.
+
+use super::*;
+use crate::*;
+use arrayvec::{ArrayString, ArrayVec};
+
+/// Script instantiation. Used to return from enum without [`Box`].
+pub(super) const LATIN: Latin = Latin {};
+
+/// Latin romanization.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Latin {}
+
+impl Extension for Latin {
+	fn name(&self) -> &str {
+		return "Latin";
+	}
+
+	fn from_concept(&self, string: &str) -> Result<Concept> {
+		let mut radicals = ArrayVec::<[_; MAX_LENGTH]>::new();
+
+		for symbol in split(string).ok_or(Error::ConceptRadicalInvalid)? {
+			match symbol {
+				Symbol::Radical(id) => radicals.try_push(Radical::from_id(id)?).map_err(|_| return Error::LengthInvalid)?,
+				Symbol::Vowel(_) => return Err(Error::ConceptRadicalInvalid),
+			}
+		}
+
+		return Concept::from_radicals(&radicals);
+	}
+
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		let mut all = Vec::new();
+
+		if let Ok(concept) = self.from_concept(string) {
+			all.push(ConceptOrWord::Concept(concept));
+		}
+
+		let symbols = split(string).ok_or(Error::WordInvalid)?;
+		let config = Config::current();
+
+		// words are matched symbol by symbol against the stem templates without building them
+		for length in &Length::all() {
+			for (stem_index, template) in config.templates(*length).iter().enumerate() {
+				let structure = template.letters();
+
+				let concept = if let Some(concept) = match_radicals(*length, structure, &symbols) {
+					concept
+				} else {
+					continue;
+				};
+
+				for (form_index, form) in config.forms(*length).iter().enumerate() {
+					let vowels_match = structure.iter().zip(&symbols).all(|(letter, symbol)| {
+						return match letter {
+							Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => *symbol == Symbol::Vowel(form.get(*vowel)),
+							Letter::Consonant(..) | Letter::DuplicateConsonant(..) => true,
+						};
+					});
+
+					if vowels_match {
+						all.push(ConceptOrWord::Word(Word::from_concept(concept, stem_index.pinto(), form_index.pinto())?));
+					}
+				}
+			}
+		}
+
+		if all.is_empty() {
+			return Err(Error::WordInvalid);
+		} else {
+			return Ok(all);
+		}
+	}
+
+	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]> {
+		let mut string = ArrayString::new();
+
+		for radical in concept.radicals() {
+			string.push_str(script_radicals()[usize::from(radical.id())]);
+		}
+
+		return string;
+	}
+
+	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]> {
+		let mut string = ArrayString::new();
+
+		for letter in letters(word) {
+			string.push_str(letter);
+		}
+
+		return string;
+	}
+
+	fn orthography(&self) -> Option<&dyn Orthography> {
+		return Some(self);
+	}
+
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return Some(self);
+	}
+}
+
+impl Orthography for Latin {
+	fn confusables(&self) -> &'static [(&'static str, &'static str)] {
+		// uppercase letters and dotless `ı`
+		return &[
+			("A", "a"),
+			("B", "b"),
+			("C", "c"),
+			("D", "d"),
+			("E", "e"),
+			("F", "f"),
+			("G", "g"),
+			("H", "h"),
+			("I", "i"),
+			("J", "j"),
+			("K", "k"),
+			("L", "l"),
+			("M", "m"),
+			("N", "n"),
+			("P", "p"),
+			("Q", "q"),
+			("R", "r"),
+			("S", "s"),
+			("T", "t"),
+			("U", "u"),
+			("V", "v"),
+			("W", "w"),
+			("X", "x"),
+			("Y", "y"),
+			("Z", "z"),
+			("ı", "i"),
+		];
+	}
+}
+
+impl Lettering for Latin {
+	fn letters(&self, word: Word) -> Vec<String> {
+		return letters(word).iter().map(|letter| return (*letter).to_owned()).collect();
+	}
+
+	fn required_radicals(&self, string: &str) -> Vec<Radical> {
+		// spellings are uniquely decodable, so whole letters can only be split one way
+		return split(string)
+			.into_iter()
+			.flatten()
+			.filter_map(|symbol| {
+				return match symbol {
+					Symbol::Radical(id) => Radical::from_id(id).ok(),
+					Symbol::Vowel(_) => None,
+				};
+			})
+			.collect();
+	}
+
+	fn independent_letters(&self) -> bool {
+		return true;
+	}
+}
+
+/// Get what is written for every letter of the stem template.
+fn letters(word: Word) -> ArrayVec<[&'static str; 8]> {
+	let concept_radicals = word.concept().radicals();
+	let form = word.form();
+
+	return word
+		.structure()
+		.iter()
+		.map(|letter| {
+			return match letter {
+				Letter::Consonant(radical_index) | Letter::DuplicateConsonant(radical_index) => {
+					script_radicals()[usize::from(concept_radicals[usize::from(*radical_index)].id())]
+				},
+				Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => script_vowel(form.get(*vowel)),
+			};
+		})
+		.collect();
+}
+
+/// Get radicals for this script.
+/// Every radical is a letter, optionally followed by `h` or `y`, which are never written alone. `hh` is the only radical starting with `h`.
+/// This keeps the spellings uniquely decodable: no string can be split into radicals and vowels in more then one way.
+const fn script_radicals() -> [&'static str; NUM_OF_RADICALS] {
+	return [
+		"q", "j", "w", "hh", "qh", "xh", "k", "kh", "x", "ky", "gh", "jh", "g", "gy", "wy", "zh", "jy", "sh", "xy", "ch", "s", "sy", "z", "zy", "d",
+		"dy", "t", "th", "cy", "c", "fh", "dh", "v", "f", "p", "b", "m", "n", "nh", "rh", "r", "ly", "lh", "l",
+	];
+}
+
+/// Get vowel for this script.
+const fn script_vowel(vowel: FormVowel) -> &'static str {
+	return match vowel {
+		FormVowel::A => "a",
+		FormVowel::E => "e",
+		FormVowel::I => "i",
+		FormVowel::U => "u",
+	};
+}
+
+/// Radical or vowel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Symbol {
+	/// Radical id.
+	Radical(u8),
+	/// Vowel.
+	Vowel(FormVowel),
+}
+
+/// Split `string` into [`Symbol`]s, [`None`] if it can't be split or is too long to be a word.
+fn split(string: &str) -> Option<ArrayVec<[Symbol; 8]>> {
+	let mut symbols = ArrayVec::new();
+
+	if split_rest(string, &mut symbols) {
+		return Some(symbols);
+	} else {
+		return None;
+	}
+}
+
+/// Split what is left of the string, see [`split`].
+/// A symbol can be the start of another one followed by something else, e.g. `shh` is `s` and `hh`, so we have to backtrack.
+fn split_rest(string: &str, symbols: &mut ArrayVec<[Symbol; 8]>) -> bool {
+	if string.is_empty() {
+		return true;
+	}
+
+	let radicals = script_radicals();
+	let vowels = FormVowel::all();
+	let radicals = radicals
+		.iter()
+		.enumerate()
+		.map(|(id, radical)| return (Symbol::Radical(id.pinto()), *radical));
+	let vowels = vowels.iter().map(|vowel| return (Symbol::Vowel(*vowel), script_vowel(*vowel)));
+
+	for (symbol, spelling) in radicals.chain(vowels) {
+		if let Some(string_left) = string.strip_prefix(spelling) {
+			if symbols.try_push(symbol).is_ok() {
+				if split_rest(string_left, symbols) {
+					return true;
+				}
+
+				symbols.pop();
+			}
+		}
+	}
+
+	return false;
+}
+
+/// Match the radicals of `symbols` against `structure` of `length`, [`None`] if a radical or vowel is in the wrong place, a radical isn't part of
+/// the radical inventory or the radicals don't make up a [`Concept`].
+fn match_radicals(length: Length, structure: &[Letter], symbols: &[Symbol]) -> Option<Concept> {
+	if structure.len() != symbols.len() {
+		return None;
+	}
+
+	let mut radicals = [None; MAX_LENGTH];
+
+	for (letter, symbol) in structure.iter().zip(symbols) {
+		match (letter, symbol) {
+			(Letter::Consonant(index) | Letter::DuplicateConsonant(index), Symbol::Radical(id)) => {
+				let radical = Radical::from_id(*id).ok()?;
+
+				// a duplicate has to repeat its radical
+				if *radicals[usize::from(*index)].get_or_insert(radical) != radical {
+					return None;
+				}
+			},
+			(Letter::Vowel(..) | Letter::DuplicateVowel(..) | Letter::Nasal(..), Symbol::Vowel(_)) => (),
+			_ => return None,
+		}
+	}
+
+	// every radical of the concept has to be in the word
+	let radicals: ArrayVec<[_; MAX_LENGTH]> = radicals[..length.as_int()].iter().copied().collect::<Option<_>>()?;
+
+	return Concept::from_radicals(&radicals).ok();
+}
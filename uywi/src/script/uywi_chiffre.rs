@@ -15,15 +15,15 @@ pub(super) const UYWI_CHIFFRE: UywiChiffre = UywiChiffre {};
 pub(super) struct UywiChiffre {}
 
 impl Extension for UywiChiffre {
-	fn script(&self) -> Script {
-		return Script::UywiChiffre;
+	fn name(&self) -> &str {
+		return "Uywi Chiffre";
 	}
 
 	fn from_concept(&self, string: &str) -> Result<Concept> {
 		let length = Length::new(string.grapheme_len())?;
 
 		// store already used radicals
-		let mut radicals = ArrayVec::<[_; 4]>::new();
+		let mut radicals = ArrayVec::<[_; MAX_LENGTH]>::new();
 
 		// iterate through
 		for (position, order) in length.radical_order_mirrored().iter().enumerate() {
@@ -35,7 +35,7 @@ impl Extension for UywiChiffre {
 				.iter()
 				.position(|script_radical| return *script_radical == radical)
 				.ok_or(Error::ConceptRadicalInvalid)?;
-			let radical = Radical::from_index(radical_index.pinto());
+			let radical = Radical::from_id(radical_index.pinto())?;
 
 			// get radicals in appropriate order for this position and filter already used radicals
 			let mut radicals_ordered = length.radicals_ordered(position, &radicals);
@@ -72,38 +72,49 @@ impl Extension for UywiChiffre {
 		return Ok(Concept::new(radicals, length));
 	}
 
-	fn from_str(&self, string: &str) -> Result<ConceptOrWord> {
-		let mut concept = ArrayString::<[u8; WORD_BUFFER]>::new();
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		let mut all = Vec::new();
 
 		if let Ok(concept) = self.from_concept(string) {
-			return Ok(ConceptOrWord::Concept(concept));
-		} else {
-			for length in &[Length::L2, Length::L3, Length::L4] {
-				for stem_index in 0..length.stems_per_concept() {
-					let stem_index = stem_index.pinto();
-					let structure = structure::structures(*length, stem_index);
-
-					if structure.len() == string.grapheme_len() {
-						for (letter_structure, letter_concept) in structure.iter().zip(string.graphemes(true)) {
-							if let Letter::Consonant(_) = letter_structure {
-								concept.try_push_str(letter_concept).map_err(|_| return Error::WordLengthInvalid)?
-							}
-						}
+			all.push(ConceptOrWord::Concept(concept));
+		}
 
-						if let Ok(concept) = self.from_concept(&concept) {
-							for word in Words::new(concept, stem_index) {
-								if string == word.to_string(self.script()) {
-									return Ok(ConceptOrWord::Word(word));
-								}
-							}
+		// every grapheme is read once, words are then matched letter by letter against the stem templates without building them
+		if let Some(symbols) = symbols(string) {
+			let config = Config::current();
+
+			for length in &Length::all() {
+				for (stem_index, template) in config.templates(*length).iter().enumerate() {
+					let structure = template.letters();
+
+					let concept = if let Some(concept) = match_radicals(*length, structure, &symbols) {
+						concept
+					} else {
+						continue;
+					};
+
+					for (form_index, form) in config.forms(*length).iter().enumerate() {
+						let vowels_match = structure.iter().zip(&symbols).all(|(letter, symbol)| {
+							return match letter {
+								Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => {
+									*symbol == Symbol::Vowel(script_vowel(form.get(*vowel)))
+								},
+								Letter::Consonant(..) | Letter::DuplicateConsonant(..) => true,
+							};
+						});
+
+						if vowels_match {
+							all.push(ConceptOrWord::Word(Word::from_concept(concept, stem_index.pinto(), form_index.pinto())?));
 						}
 					}
-
-					concept.clear();
 				}
 			}
+		}
 
+		if all.is_empty() {
 			return Err(Error::WordInvalid);
+		} else {
+			return Ok(all);
 		}
 	}
 
@@ -111,7 +122,7 @@ impl Extension for UywiChiffre {
 		let mut string = ArrayString::new();
 
 		for radical in concept.radicals() {
-			string.push_str(script_radicals()[usize::from(radical.index())]);
+			string.push_str(script_radicals()[usize::from(radical.id())]);
 		}
 
 		return string;
@@ -121,8 +132,8 @@ impl Extension for UywiChiffre {
 		let concept = word.concept();
 		// get concept radicals
 		let concept_radicals = concept.radicals();
-		// get correct form config
-		let vowels = form_configs(concept.length(), word.form_index());
+		// get correct form
+		let form = word.form();
 		// get correct structure
 		let structure = structure::structures(concept.length(), word.stem_index());
 
@@ -133,16 +144,58 @@ impl Extension for UywiChiffre {
 			match letter {
 				Letter::Consonant(radical_index) | Letter::DuplicateConsonant(radical_index) => {
 					let concept_radical_index = usize::from(radical_index);
-					let radical_index = usize::from(concept_radicals[concept_radical_index].index());
+					let radical_index = usize::from(concept_radicals[concept_radical_index].id());
 
 					string.push_str(script_radicals()[radical_index])
 				},
-				Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => string.push_str(vowels.get(vowel)),
+				Letter::Vowel(vowel) | Letter::DuplicateVowel(vowel) | Letter::Nasal(vowel) => string.push_str(script_vowel(form.get(vowel))),
 			};
 		}
 
 		return string;
 	}
+
+	fn orthography(&self) -> Option<&dyn Orthography> {
+		return Some(self);
+	}
+
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return Some(self);
+	}
+}
+
+impl Orthography for UywiChiffre {
+	fn confusables(&self) -> &'static [(&'static str, &'static str)] {
+		// dotted `i` and Cyrillic `о`, `O` and `0` are both radicals, so neither can stand in for the other
+		return &[("i", "ı"), ("о", "o")];
+	}
+
+	fn alphabet(&self) -> &'static [&'static str] {
+		// radicals in their order, followed by the vowels
+		return &[
+			"?", "Y", "w", "h", "2", "H", "K", "k", "X", "x", "8", "4", "G", "g", "j", "7", "3", "Q", "c", "9", "S", "s", "Z", "z", "D", "d", "T",
+			"t", "P", "0", "B", "6", "V", "f", "p", "b", "m", "n", "O", "R", "r", "1", "L", "l", "o", "ı",
+		];
+	}
+}
+
+impl Lettering for UywiChiffre {
+	fn letters(&self, word: Word) -> Vec<String> {
+		// every letter is one grapheme
+		return self.word(word).graphemes(true).map(str::to_owned).collect();
+	}
+
+	fn required_radicals(&self, string: &str) -> Vec<Radical> {
+		return string
+			.graphemes(true)
+			.filter_map(|grapheme| return script_radicals().iter().position(|radical| return *radical == grapheme))
+			.filter_map(|id| return Radical::from_id(id.pinto()).ok())
+			.collect();
+	}
+
+	fn independent_letters(&self) -> bool {
+		return true;
+	}
 }
 
 /// Get radicals for this script.
@@ -153,36 +206,71 @@ const fn script_radicals() -> [&'static str; NUM_OF_RADICALS] {
 	];
 }
 
-/// Get vowels for this script.
-const fn script_vowels() -> [&'static str; 2] {
-	return ["o", "ı"];
+/// Get vowel for this script, Chiffre only tells apart open and close vowels.
+const fn script_vowel(vowel: FormVowel) -> &'static str {
+	return match vowel {
+		FormVowel::A | FormVowel::E => "o",
+		FormVowel::I | FormVowel::U => "ı",
+	};
+}
+
+/// Radical or vowel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Symbol {
+	/// Radical.
+	Radical(Radical),
+	/// Vowel as it is written.
+	Vowel(&'static str),
 }
 
-/// List how forms are configured.
-fn form_configs(length: Length, form_index: u8) -> Vowels {
-	let mut configs = ArrayVec::<[_; 4]>::new();
-	let [o, i] = script_vowels();
+/// Read every grapheme of `string` as a [`Symbol`], [`None`] if one isn't part of this script or it's too long to be a word.
+fn symbols(string: &str) -> Option<ArrayVec<[Symbol; 8]>> {
+	let mut symbols = ArrayVec::new();
 
-	match length {
-		Length::L2 => configs.try_extend_from_slice(&[Vowels(o, o), Vowels(i, i)]),
-		Length::L3 | Length::L4 => configs.try_extend_from_slice(&[Vowels(o, o), Vowels(o, i), Vowels(i, o), Vowels(i, i)]),
+	for grapheme in string.graphemes(true) {
+		let symbol = if let Some(id) = script_radicals().iter().position(|radical| return *radical == grapheme) {
+			Symbol::Radical(Radical::from_id(id.pinto()).ok()?)
+		} else {
+			Symbol::Vowel(
+				FormVowel::all()
+					.iter()
+					.map(|vowel| return script_vowel(*vowel))
+					.find(|vowel| return *vowel == grapheme)?,
+			)
+		};
+
+		symbols.try_push(symbol).ok()?;
 	}
-	.expect("failed to fill form configs");
 
-	return configs[usize::from(form_index)];
+	return Some(symbols);
 }
 
-/// Stores vowels.
-/// Purely there to make it easier to extract vowels with [`Vowel`].
-#[derive(Clone, Copy, Debug)]
-struct Vowels(&'static str, &'static str);
-
-impl Vowels {
-	/// Gets the right vowel with [`Vowel`].
-	fn get(&self, vowel: Vowel) -> &'static str {
-		return match vowel {
-			Vowel::First => self.0,
-			Vowel::Last => self.1,
-		};
+/// Match the radicals of `symbols` against `structure` of `length`, [`None`] if a radical or vowel is in the wrong place or the radicals don't make
+/// up a [`Concept`].
+fn match_radicals(length: Length, structure: &[Letter], symbols: &[Symbol]) -> Option<Concept> {
+	if structure.len() != symbols.len() {
+		return None;
 	}
+
+	let mut radicals = [None; MAX_LENGTH];
+
+	for (letter, symbol) in structure.iter().zip(symbols) {
+		match (letter, symbol) {
+			(Letter::Consonant(index) | Letter::DuplicateConsonant(index), Symbol::Radical(radical)) => {
+				let index = usize::from(*index);
+
+				// a duplicate has to repeat its radical
+				if *radicals[index].get_or_insert(*radical) != *radical {
+					return None;
+				}
+			},
+			(Letter::Vowel(..) | Letter::DuplicateVowel(..) | Letter::Nasal(..), Symbol::Vowel(_)) => (),
+			_ => return None,
+		}
+	}
+
+	// every radical of the concept has to be in the word
+	let radicals: ArrayVec<[_; MAX_LENGTH]> = radicals[..length.as_int()].iter().copied().collect::<Option<_>>()?;
+
+	return Concept::from_radicals(&radicals).ok();
 }
@@ -0,0 +1,214 @@
+//! X-SAMPA, ASCII version of IPA (Peter's script).
+
+use super::*;
+use crate::*;
+use arrayvec::{Array, ArrayString};
+
+/// Script instantiation. Used to return from enum without [`Box`].
+pub(super) const X_SAMPA: XSampa = XSampa {};
+
+/// X-SAMPA.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct XSampa {}
+
+impl Extension for XSampa {
+	fn name(&self) -> &str {
+		return "X-SAMPA (Peter's script)";
+	}
+
+	fn from_concept(&self, string: &str) -> Result<Concept> {
+		return IPA_PETER.from_concept(&to_ipa(string).ok_or(Error::ConceptRadicalInvalid)?);
+	}
+
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>> {
+		return IPA_PETER.parse_all(&to_ipa(string).ok_or(Error::WordInvalid)?);
+	}
+
+	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]> {
+		return from_ipa(&IPA_PETER.concept(concept));
+	}
+
+	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]> {
+		return from_ipa(&IPA_PETER.word(word));
+	}
+
+	fn phonology(&self) -> Option<&dyn Phonology> {
+		return Some(self);
+	}
+
+	fn orthography(&self) -> Option<&dyn Orthography> {
+		return Some(self);
+	}
+
+	fn lettering(&self) -> Option<&dyn Lettering> {
+		return Some(self);
+	}
+}
+
+impl Phonology for XSampa {
+	fn derivation(&self, word: Word) -> Derivation {
+		let derivation = IPA_PETER.derivation(word);
+		let segments = |segments: &[String]| {
+			return segments
+				.iter()
+				.map(|segment| return from_ipa::<[u8; WORD_BUFFER]>(segment).to_string())
+				.collect();
+		};
+		let steps = derivation
+			.steps()
+			.iter()
+			.map(|step| {
+				let changes = step
+					.changes()
+					.iter()
+					.map(|change| {
+						return Change::new(
+							change.position(),
+							from_ipa::<[u8; WORD_BUFFER]>(change.before()).to_string(),
+							from_ipa::<[u8; WORD_BUFFER]>(change.after()).to_string(),
+						);
+					})
+					.collect();
+
+				return Step::new(step.rule(), step.assimilation(), segments(step.segments()), changes);
+			})
+			.collect();
+
+		return Derivation::new(segments(derivation.base()), steps, derivation.vowel_features().to_vec());
+	}
+
+	fn syllables(&self, word: Word) -> Vec<Syllable> {
+		let string = IPA_PETER.word(word);
+		let mut position = 0;
+
+		// syllables never split a symbol, so every syllable can be replaced on its own
+		return IPA_PETER
+			.syllables(word)
+			.into_iter()
+			.map(|syllable| {
+				let start = position;
+				position = position.padd(from_ipa::<[u8; WORD_BUFFER]>(&string[syllable.range()]).len());
+
+				return syllable.with_range(start..position);
+			})
+			.collect();
+	}
+}
+
+impl Orthography for XSampa {
+	fn alphabet(&self) -> &'static [&'static str] {
+		// same order as IPA (Peter's script)
+		return &[
+			"a", "A~", "{", "b", "c", "C", "d", "d_z`", "d_Z", "D", "e", "E~", "f", "g", "h", "X\\", "i", "1", "j", "J\\", "k", "k_h", "l", "L\\",
+			"m", "n", "N", "O", "O~", "2", "9~", "p", "r", "r\\`", "R", "s", "s`", "s\\", "S", "t", "t_h", "t_s\\", "t_s", "T", "u", "v", "w", "x",
+			"X", "y", "H", "z", "?", "?\\", ":", "'", "_?\\",
+		];
+	}
+}
+
+impl Lettering for XSampa {
+	fn letters(&self, word: Word) -> Vec<String> {
+		return IPA_PETER
+			.letters(word)
+			.iter()
+			.map(|letter| return from_ipa::<[u8; WORD_BUFFER]>(letter).to_string())
+			.collect();
+	}
+}
+
+/// IPA symbols that aren't written the same in X-SAMPA, together with their replacement.
+/// Symbols are replaced longest first, so no replacement may start with another one followed by something it could be confused with.
+const SYMBOLS: [(&str, &str); 28] = [
+	// affricates, the tie bar is written as `_`
+	("d͡ʐ", "d_z`"),
+	("d͡ʒ", "d_Z"),
+	("t͡ɕ", "t_s\\"),
+	("t͡s", "t_s"),
+	// consonants
+	("ʔ", "?"),
+	("ʕ", "?\\"),
+	("ħ", "X\\"),
+	("ç", "C"),
+	("ʁ", "R"),
+	("χ", "X"),
+	("ɟ", "J\\"),
+	("ɥ", "H"),
+	("ʂ", "s`"),
+	("ɕ", "s\\"),
+	("ʃ", "S"),
+	("θ", "T"),
+	("ð", "D"),
+	("ŋ", "N"),
+	("ɻ", "r\\`"),
+	("ʟ", "L\\"),
+	// vowels
+	("ɑ̃", "A~"),
+	("ɔ̃", "O~"),
+	("ɛ̃", "E~"),
+	("œ̃", "9~"),
+	("æ", "{"),
+	("ɨ", "1"),
+	("ɔ", "O"),
+	("ø", "2"),
+];
+
+/// Diacritics that aren't written the same in X-SAMPA, together with their replacement.
+const DIACRITICS: [(&str, &str); 4] = [("ʰ", "_h"), ("ˤ", "_?\\"), ("ʲ", "'"), ("ː", ":")];
+
+/// Letters that are written the same in IPA and X-SAMPA.
+const LETTERS: &str = "abcdefghijklmnprstuvwxyz";
+
+/// Replace every IPA symbol with its X-SAMPA symbol.
+fn from_ipa<A: Array<Item = u8> + Copy>(ipa: &str) -> ArrayString<A> {
+	let mut x_sampa = ArrayString::new();
+	let mut ipa_left = ipa;
+
+	while !ipa_left.is_empty() {
+		let (symbol, replacement) =
+			replace(ipa_left, |(symbol, _)| return symbol, |(_, replacement)| return replacement).expect("no X-SAMPA symbol found");
+
+		x_sampa.push_str(replacement);
+		ipa_left = &ipa_left[symbol.len()..];
+	}
+
+	return x_sampa;
+}
+
+/// Replace every X-SAMPA symbol with its IPA symbol, [`None`] if the string isn't valid X-SAMPA of this script.
+fn to_ipa(x_sampa: &str) -> Option<String> {
+	let mut ipa = String::with_capacity(x_sampa.len().pmul(2));
+	let mut x_sampa_left = x_sampa;
+
+	while !x_sampa_left.is_empty() {
+		let (symbol, replacement) = replace(x_sampa_left, |(_, symbol)| return symbol, |(replacement, _)| return replacement)?;
+
+		ipa.push_str(replacement);
+		x_sampa_left = &x_sampa_left[symbol.len()..];
+	}
+
+	return Some(ipa);
+}
+
+/// Find the longest symbol `string` starts with, returns the symbol and its replacement.
+/// `from` and `to` pick the side of [`SYMBOLS`] and [`DIACRITICS`] to replace from and to.
+fn replace(
+	string: &str,
+	from: impl Fn((&'static str, &'static str)) -> &'static str,
+	to: impl Fn((&'static str, &'static str)) -> &'static str,
+) -> Option<(&'static str, &'static str)> {
+	if let Some(pair) = SYMBOLS
+		.iter()
+		.chain(&DIACRITICS)
+		.copied()
+		.filter(|pair| return string.starts_with(from(*pair)))
+		.max_by_key(|pair| return from(*pair).len())
+	{
+		return Some((from(pair), to(pair)));
+	}
+
+	// everything else has to be a letter that is written the same
+	let position = LETTERS.find(string.get(..1)?)?;
+	let letter = &LETTERS[position..position.padd(1)];
+
+	return Some((letter, letter));
+}
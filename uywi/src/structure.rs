@@ -2,9 +2,16 @@
 
 use crate::*;
 use arrayvec::ArrayVec;
+use std::{
+	fmt::{self, Display, Formatter},
+	str::FromStr,
+};
+
+/// Maximum number of letters in a stem template, and so in a word.
+pub(crate) const MAX_LETTERS: usize = 8;
 
 /// Represents a consonant or a vowel in a structure.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Letter {
 	/// Represents a consonant position.
 	Consonant(u8),
@@ -18,63 +25,269 @@ pub enum Letter {
 	Nasal(Vowel),
 }
 
-impl Letter {
-	/// Build new [`Letter::Consonant`].
-	pub(crate) fn new_consonant(length: Length, index: u8) -> Self {
-		assert!(usize::from(index) < length.as_int(), "consonant index is invalid");
+/// Represents a vowel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Vowel {
+	/// Represents the first vowel.
+	First,
+	/// Represents the second vowel.
+	Last,
+}
 
-		return Self::Consonant(index);
+/// Represents a vowel used by a [`Form`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FormVowel {
+	/// Represents `a`.
+	A,
+	/// Represents `e`.
+	E,
+	/// Represents `i`.
+	I,
+	/// Represents `u`.
+	U,
+}
+
+impl FormVowel {
+	/// All [`FormVowel`]s.
+	#[must_use]
+	pub const fn all() -> [Self; 4] {
+		return [Self::A, Self::E, Self::I, Self::U];
 	}
 
-	/// Build new [`Letter::Vowel`].
-	pub(crate) fn new_vowel(index: usize) -> Self {
-		return Self::Vowel(match index {
-			0 => Vowel::First,
-			1 => Vowel::Last,
-			_ => unreachable!("vowel index is invalid"),
-		});
+	/// Get phonological features of the neutral vowel, before any assimilation.
+	/// Features of the vowels a word is written with are in [`Derivation::vowel_features`](crate::Derivation::vowel_features).
+	#[must_use]
+	pub const fn features(self) -> VowelFeatures {
+		return script::vowel_features(self);
 	}
 
-	/// Build new [`Letter::DuplicateConsonant`].
-	pub(crate) fn new_duplicate_consonant(length: Length, index: u8) -> Self {
-		assert!(usize::from(index) < length.as_int(), "consonant index is invalid");
+	/// Get in `str` form.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		return match self {
+			Self::A => "a",
+			Self::E => "e",
+			Self::I => "i",
+			Self::U => "u",
+		};
+	}
+}
+
+impl FromStr for FormVowel {
+	type Err = Error;
+
+	/// Parse [`FormVowel`].
+	/// # Errors
+	/// [`Error::FormVowelInvalid`] if `string` isn't a [`FormVowel`]
+	fn from_str(string: &str) -> Result<Self> {
+		return Self::all()
+			.iter()
+			.copied()
+			.find(|vowel| return vowel.as_str() == string)
+			.ok_or(Error::FormVowelInvalid);
+	}
+}
 
-		return Self::DuplicateConsonant(index);
+impl Display for FormVowel {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		return write!(formatter, "{}", self.as_str());
 	}
+}
+
+/// Form, decides which [`FormVowel`]s fill the vowels of a stem.
+///
+/// Forms are written as two vowels separated by whitespace, e.g. `a e`, the first fills [`Vowel::First`] and the second [`Vowel::Last`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Form {
+	/// Vowel for [`Vowel::First`].
+	first: FormVowel,
+	/// Vowel for [`Vowel::Last`].
+	last: FormVowel,
+}
 
-	/// Build new [`Letter::DuplicateVowel`].
-	pub(crate) fn new_duplicate_vowel(index: usize) -> Self {
-		return Self::DuplicateVowel(match index {
-			0 => Vowel::First,
-			1 => Vowel::Last,
-			_ => unreachable!("vowel index is invalid"),
-		});
+impl Form {
+	/// Build new [`Form`].
+	#[must_use]
+	pub const fn new(first: FormVowel, last: FormVowel) -> Self {
+		return Self { first, last };
 	}
 
-	/// Build new [`Letter::Nasal`].
-	pub(crate) fn new_nasal(index: usize) -> Self {
-		return Self::Nasal(match index {
-			0 => Vowel::First,
-			1 => Vowel::Last,
-			_ => unreachable!("vowel index is invalid"),
-		});
+	/// Get the [`FormVowel`] that fills a [`Vowel`].
+	#[must_use]
+	pub const fn get(self, vowel: Vowel) -> FormVowel {
+		return match vowel {
+			Vowel::First => self.first,
+			Vowel::Last => self.last,
+		};
 	}
 }
 
-/// Represents a vowel.
-#[derive(Clone, Copy, Debug)]
-pub enum Vowel {
-	/// Represents the first vowel.
-	First,
-	/// Represents the second vowel.
-	Last,
+impl FromStr for Form {
+	type Err = Error;
+
+	/// Parse [`Form`].
+	/// # Errors
+	/// [`Error::FormVowelInvalid`] if `string` doesn't consist of two valid [`FormVowel`]s
+	fn from_str(string: &str) -> Result<Self> {
+		if let [first, last] = string.split_whitespace().collect::<Vec<_>>().as_slice() {
+			return Ok(Self::new(first.parse()?, last.parse()?));
+		} else {
+			return Err(Error::FormVowelInvalid);
+		}
+	}
+}
+
+impl Display for Form {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		return write!(formatter, "{} {}", self.first, self.last);
+	}
 }
 
-/// List of how stems are configured.
+/// Stem template, describes how consonants and vowels are arranged in a stem.
+///
+/// Templates are written as letters separated by whitespace:
+/// - `c0`: consonant, the number is the position of the radical in the concept
+/// - `v0`: vowel, `0` for the first and `1` for the last vowel of a [`Form`]
+/// - `xc0`: duplicate consonant, has to follow its consonant
+/// - `xv0`: duplicate vowel
+/// - `xn0`: nasal vowel
+#[derive(Clone, Debug, PartialEq)]
+pub struct StemTemplate {
+	/// Letters of the stem.
+	letters: ArrayVec<[Letter; 8]>,
+}
+
+impl StemTemplate {
+	/// Get letters.
+	#[must_use]
+	pub fn letters(&self) -> &[Letter] {
+		return &self.letters;
+	}
+
+	/// Validate [`StemTemplate`] against a [`Length`].
+	/// # Errors
+	/// [`Error::TemplateConsonantInvalid`] if a consonant position is higher then the [`Length`] allows
+	///
+	/// [`Error::TemplateConsonantDuplicate`] if a consonant is used twice
+	///
+	/// [`Error::TemplateConsonantMissing`] if a consonant is missing
+	pub fn validate(&self, length: Length) -> Result<()> {
+		for position in 0..length.as_int() {
+			let count = self
+				.letters
+				.iter()
+				.filter(|letter| return matches!(letter, Letter::Consonant(index) if usize::from(*index) == position))
+				.count();
+
+			match count {
+				0 => return Err(Error::TemplateConsonantMissing(position)),
+				1 => (),
+				_ => return Err(Error::TemplateConsonantDuplicate(position)),
+			}
+		}
+
+		for (position, letter) in self.letters.iter().enumerate() {
+			if let Letter::Consonant(index) | Letter::DuplicateConsonant(index) = letter {
+				if usize::from(*index) >= length.as_int() {
+					return Err(Error::TemplateConsonantInvalid(position));
+				}
+			}
+		}
+
+		return Ok(());
+	}
+}
+
+impl FromStr for StemTemplate {
+	type Err = Error;
+
+	/// Parse [`StemTemplate`].
+	/// # Errors
+	/// [`Error::TemplateEmpty`] if `string` contains no letters
+	///
+	/// [`Error::TemplateLetterInvalid`] if a letter isn't valid
+	///
+	/// [`Error::TemplateDuplicateInvalid`] if a duplicate consonant comes before its consonant
+	///
+	/// [`Error::TemplateTooLong`] if `string` contains more then 8 letters
+	fn from_str(string: &str) -> Result<Self> {
+		let mut letters = ArrayVec::new();
+
+		for (position, letter) in string.split_whitespace().enumerate() {
+			// the index is always the last character
+			let (r#type, index) = letter.split_at(letter.char_indices().last().map_or(0, |(index, _)| return index));
+			let index: u8 = index.parse().map_err(|_| return Error::TemplateLetterInvalid(position))?;
+			let vowel = || {
+				return match index {
+					0 => Ok(Vowel::First),
+					1 => Ok(Vowel::Last),
+					_ => Err(Error::TemplateLetterInvalid(position)),
+				};
+			};
+
+			let letter = match r#type {
+				"c" => Letter::Consonant(index),
+				"v" => Letter::Vowel(vowel()?),
+				"xc" => {
+					// duplicates can only duplicate something that is already there
+					if !letters
+						.iter()
+						.any(|letter| return matches!(letter, Letter::Consonant(consonant) if *consonant == index))
+					{
+						return Err(Error::TemplateDuplicateInvalid(position));
+					}
+
+					Letter::DuplicateConsonant(index)
+				},
+				"xv" => Letter::DuplicateVowel(vowel()?),
+				"xn" => Letter::Nasal(vowel()?),
+				_ => return Err(Error::TemplateLetterInvalid(position)),
+			};
+
+			letters.try_push(letter).map_err(|_| return Error::TemplateTooLong)?;
+		}
+
+		if letters.is_empty() {
+			return Err(Error::TemplateEmpty);
+		}
+
+		return Ok(Self { letters });
+	}
+}
+
+impl Display for StemTemplate {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		for (position, letter) in self.letters.iter().enumerate() {
+			if position > 0 {
+				write!(formatter, " ")?;
+			}
+
+			let vowel = |vowel: &Vowel| {
+				return match vowel {
+					Vowel::First => 0,
+					Vowel::Last => 1,
+				};
+			};
+
+			match letter {
+				Letter::Consonant(index) => write!(formatter, "c{}", index)?,
+				Letter::Vowel(vowel_type) => write!(formatter, "v{}", vowel(vowel_type))?,
+				Letter::DuplicateConsonant(index) => write!(formatter, "xc{}", index)?,
+				Letter::DuplicateVowel(vowel_type) => write!(formatter, "xv{}", vowel(vowel_type))?,
+				Letter::Nasal(vowel_type) => write!(formatter, "xn{}", vowel(vowel_type))?,
+			}
+		}
+
+		return Ok(());
+	}
+}
+
+/// List of how stems are configured by default.
 pub fn list(length: Length) -> ArrayVec<[&'static str; 8]> {
 	let mut configs = ArrayVec::new();
 
 	match length {
+		Length::L1 => configs.try_extend_from_slice(&["c0 v0", "v0 c0", "c0 v0 xv0"]),
 		Length::L2 => configs.try_extend_from_slice(&["c0 v0 c1", "c0 v0 xv0 c1", "c0 v0 c1 xc1 xn0"]),
 		Length::L3 => configs.try_extend_from_slice(&[
 			"c0 v0 c1 v1 c2",
@@ -93,60 +306,27 @@ pub fn list(length: Length) -> ArrayVec<[&'static str; 8]> {
 			"c0 v0 c1 v0 c2 xc2 v1 c3",
 			"c0 v0 c1 v0 c2 v1 xv1 c3",
 		]),
+		Length::L5 => configs.try_extend_from_slice(&["c0 v0 c1 c2 v0 c3 v1 c4", "c0 v0 c1 v0 c2 c3 v1 c4", "c0 v0 xv0 c1 c2 v1 c3 c4"]),
 	}
 	.expect("failed to fill stem config internals");
 
 	return configs;
 }
 
-/// Stem configuration list.
-pub fn structures(length: Length, stem_index: u8) -> ArrayVec<[Letter; 8]> {
-	let mut configs = ArrayVec::<[_; 8]>::new();
-	let configs_internal = list(length);
-
-	for index in 0..length.stems_per_concept() {
-		let mut config = ArrayVec::new();
-
-		for letter in configs_internal[index].split_whitespace() {
-			debug_assert!(
-				letter.len() == 2 || letter.len() == 3,
-				"configuration option has not exactly 2 or 3 letters"
-			);
-
-			let r#type = &letter[0..1];
-
-			match r#type {
-				"c" | "v" => {
-					let index: u8 = letter[1..2].parse().expect("configuration index isn't a number");
-
-					match r#type {
-						// consonant
-						"c" => config.push(Letter::new_consonant(length, index)),
-						// vowel
-						"v" => config.push(Letter::new_vowel(usize::from(index))),
-						_ => unreachable!("configuration type not valid"),
-					}
-				},
-				"x" => {
-					let r#type = &letter[1..2];
-					let index: u8 = letter[2..3].parse().expect("configuration index isn't a number");
-
-					match r#type {
-						// consonant
-						"c" => config.push(Letter::new_duplicate_consonant(length, index)),
-						// vowel
-						"v" => config.push(Letter::new_duplicate_vowel(usize::from(index))),
-						// nasal
-						"n" => config.push(Letter::new_nasal(usize::from(index))),
-						_ => unreachable!("configuration type not valid"),
-					}
-				},
-				_ => unreachable!("configuration type not valid"),
-			}
-		}
+/// List of how forms are configured by default.
+pub fn forms(length: Length) -> ArrayVec<[&'static str; 4]> {
+	let mut configs = ArrayVec::new();
 
-		configs.push(config);
+	match length {
+		Length::L1 | Length::L2 => configs.try_extend_from_slice(&["a a", "i i"]),
+		Length::L3 | Length::L4 | Length::L5 => configs.try_extend_from_slice(&["a e", "e i", "u a", "u i"]),
 	}
+	.expect("failed to fill form config internals");
 
-	return configs[usize::from(stem_index)].clone();
+	return configs;
+}
+
+/// Stem configuration from the current [`Config`].
+pub fn structures(length: Length, stem_index: u8) -> ArrayVec<[Letter; 8]> {
+	return Config::current().templates(length)[usize::from(stem_index)].letters.clone();
 }
@@ -0,0 +1,419 @@
+//! Suggestions for strings that can't be read.
+
+use crate::*;
+use std::{
+	collections::{BTreeSet, HashSet},
+	iter,
+	sync::{Mutex, OnceLock},
+};
+
+/// Most edits a [`Suggestion`] is searched for, in letters.
+const MAX_DISTANCE: usize = 2;
+
+/// [`Alphabet`]s of the built-in [`Script`]s, collected when they are first used.
+static BUILT_IN_ALPHABETS: [OnceLock<Alphabet>; 4] = [OnceLock::new(), OnceLock::new(), OnceLock::new(), OnceLock::new()];
+
+/// [`Alphabet`]s of every [`Script::Custom`] that was used, by the address of its [`Extension`], names don't have to be unique across
+/// [`ScriptRegistry`]s.
+static CUSTOM_ALPHABETS: Mutex<Vec<(usize, &'static Alphabet)>> = Mutex::new(Vec::new());
+
+/// What is wrong with a string, return type from [`Suggestions::mistake`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mistake {
+	/// String is empty.
+	Empty,
+	/// Grapheme at this position isn't used by the [`Script`], e.g. an unknown radical.
+	GraphemeUnknown(usize),
+	/// Radical starting at this grapheme position was already used, radicals can only be used once in a [`Concept`].
+	RadicalDuplicate(usize),
+	/// All letters are known, but no stem template and form matches them.
+	TemplateMissing,
+}
+
+/// A [`Concept`] or [`Word`] close to the string that couldn't be read.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Suggestion {
+	/// Suggested [`Concept`] or [`Word`].
+	concept_or_word: ConceptOrWord,
+	/// Edit distance in letters.
+	distance: usize,
+}
+
+impl Suggestion {
+	/// Get [`ConceptOrWord`].
+	#[must_use]
+	pub const fn concept_or_word(self) -> ConceptOrWord {
+		return self.concept_or_word;
+	}
+
+	/// Get edit distance in letters to the string that couldn't be read, letters can span more then one grapheme, e.g. the IPA `t͡s`.
+	#[must_use]
+	pub const fn distance(self) -> usize {
+		return self.distance;
+	}
+}
+
+/// Return type from [`Script::suggest`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestions {
+	/// What is wrong with the string.
+	mistake: Option<Mistake>,
+	/// Suggestions, ordered by distance.
+	suggestions: Vec<Suggestion>,
+}
+
+impl Suggestions {
+	/// Get what is wrong with the string, [`None`] if it can be read.
+	#[must_use]
+	pub const fn mistake(&self) -> Option<Mistake> {
+		return self.mistake;
+	}
+
+	/// Get [`Suggestion`]s, nearest first.
+	#[must_use]
+	pub fn suggestions(&self) -> &[Suggestion] {
+		return &self.suggestions;
+	}
+}
+
+/// Find [`Concept`]s and [`Word`]s close to `string`, see [`Script::suggest`].
+pub(crate) fn suggest(script: Script, string: &str, limit: usize) -> Suggestions {
+	let alphabet = Alphabet::get(script);
+	let string = lenient::normalize(string);
+	let letters = alphabet.split(&string);
+	let texts: Vec<_> = letters.iter().map(|letter| return letter.text).collect();
+
+	let parsed = script.parse_all(&string);
+
+	let mistake = if parsed.is_ok() {
+		None
+	} else if letters.is_empty() {
+		Some(Mistake::Empty)
+	} else if let Some(letter) = letters.iter().find(|letter| return !alphabet.letters.contains(letter.text)) {
+		Some(Mistake::GraphemeUnknown(letter.position))
+	} else if let Some(position) = alphabet.duplicate(&texts) {
+		Some(Mistake::RadicalDuplicate(letters[position].position))
+	} else {
+		Some(Mistake::TemplateMissing)
+	};
+
+	let mut suggestions = Vec::new();
+	let add = |found: &mut Vec<Suggestion>, all: Vec<ConceptOrWord>| {
+		for concept_or_word in all {
+			if !found
+				.iter()
+				.any(|suggestion: &Suggestion| return suggestion.concept_or_word == concept_or_word)
+			{
+				found.push(Suggestion {
+					concept_or_word,
+					distance: distance(&texts, &alphabet.texts(&concept_or_word.to_string(script))),
+				});
+			}
+		}
+	};
+
+	// a string that can be read is its own nearest suggestion
+	if let Ok(all) = parsed {
+		add(&mut suggestions, all);
+	}
+
+	// everything one edit away, then everything one edit away from that, until there are enough suggestions
+	let mut seen = HashSet::new();
+	let mut candidates = vec![texts.clone()];
+	seen.insert(string.clone().into_owned());
+
+	for distance in 1..=MAX_DISTANCE {
+		if suggestions.len() >= limit {
+			break;
+		}
+
+		let mut edited = Vec::new();
+
+		for candidate in &candidates {
+			alphabet.edits(candidate, |edit| {
+				let edits_needed = alphabet.edits_needed(edit);
+				// candidates of the last round aren't edited again, neither are those the edits left can't fix
+				let keep = distance < MAX_DISTANCE && edits_needed <= MAX_DISTANCE.psub(distance);
+
+				// only strings that could make up a concept are parsed
+				if edits_needed > 0 && !keep {
+					return;
+				}
+
+				let edit_string = edit.concat();
+
+				if seen.contains(&edit_string) {
+					return;
+				}
+
+				if edits_needed == 0 {
+					if let Ok(all) = script.parse_all(&edit_string) {
+						add(&mut suggestions, all);
+					}
+				}
+
+				seen.insert(edit_string);
+
+				if keep {
+					edited.push(edit.to_vec());
+				}
+			});
+		}
+
+		candidates = edited;
+	}
+
+	// the radicals alone might still make up a concept, then all of its words are close
+	if let Ok(concept) = script.from_concept(&alphabet.radicals_in(&texts).concat()) {
+		add(
+			&mut suggestions,
+			iter::once(ConceptOrWord::Concept(concept))
+				.chain(concept.into_iter().flatten().map(ConceptOrWord::Word))
+				.collect(),
+		);
+	}
+
+	suggestions.sort_by_key(|suggestion| return suggestion.distance);
+	suggestions.truncate(limit);
+
+	return Suggestions { mistake, suggestions };
+}
+
+/// Get edit distance between `letters` and `other`, counting inserted, removed and replaced letters.
+fn distance(letters: &[&str], other: &[&str]) -> usize {
+	// distances of the previous row, starting with the distance to the empty string
+	let mut distances: Vec<_> = (0..=other.len()).collect();
+
+	for (position, letter) in letters.iter().enumerate() {
+		let mut previous = distances[0];
+		distances[0] = position.padd(1);
+
+		for (other_position, other_letter) in other.iter().enumerate() {
+			let replaced = if letter == other_letter { previous } else { previous.padd(1) };
+			let removed = distances[other_position.padd(1)].padd(1);
+			let inserted = distances[other_position].padd(1);
+
+			previous = distances[other_position.padd(1)];
+			distances[other_position.padd(1)] = replaced.min(removed).min(inserted);
+		}
+	}
+
+	return distances[other.len()];
+}
+
+/// Letter of a string, see [`Alphabet::split`].
+struct Split<'a> {
+	/// Letter as it is written.
+	text: &'a str,
+	/// Position of its first grapheme.
+	position: usize,
+}
+
+/// Letters a [`Script`] uses.
+#[derive(Debug)]
+pub(crate) struct Alphabet {
+	/// What is written for every letter of [`Word`]s and every radical of [`Concept`]s, see [`Lettering::letters`].
+	letters: BTreeSet<String>,
+	/// Radicals in [`Concept`] form.
+	radicals: BTreeSet<String>,
+	/// If a stem template repeats a radical apart from itself, then [`Alphabet::edits_needed`] can't count duplicates as mistakes.
+	repeats_apart: bool,
+}
+
+impl Alphabet {
+	/// Get [`Alphabet`] of `script`, it's collected once and kept for every following call.
+	/// Like [`Config`], which it's collected with, it can't change afterwards.
+	pub(crate) fn get(script: Script) -> &'static Self {
+		let built_in = match script {
+			Script::UywiChiffre => &BUILT_IN_ALPHABETS[0],
+			Script::IpaPeter => &BUILT_IN_ALPHABETS[1],
+			Script::XSampa => &BUILT_IN_ALPHABETS[2],
+			Script::Latin => &BUILT_IN_ALPHABETS[3],
+			Script::Custom(extension) => {
+				// custom scripts live for the rest of the program, so do their alphabets
+				let address = (extension as *const dyn Extension).cast::<()>() as usize;
+				let mut alphabets = CUSTOM_ALPHABETS.lock().expect("alphabet cache is poisoned");
+
+				if let Some((_, alphabet)) = alphabets.iter().find(|(key, _)| return *key == address) {
+					return alphabet;
+				}
+
+				let alphabet: &'static Self = Box::leak(Box::new(Self::new(script)));
+				alphabets.push((address, alphabet));
+
+				return alphabet;
+			},
+		};
+
+		return built_in.get_or_init(|| return Self::new(script));
+	}
+
+	/// Collect letters from all [`Concept`]s and [`Word`]s of [`Length::L1`] and [`Length::L2`].
+	fn new(script: Script) -> Self {
+		let config = Config::current();
+		let mut alphabet = Self {
+			letters: BTreeSet::new(),
+			radicals: BTreeSet::new(),
+			repeats_apart: Length::all().iter().any(|length| {
+				return config.templates(*length).iter().any(|template| {
+					let letters = template.letters();
+
+					return letters.iter().enumerate().any(|(position, letter)| {
+						return match (letter, position.checked_sub(1).map(|previous| return letters[previous])) {
+							(Letter::DuplicateConsonant(index), Some(Letter::Consonant(previous) | Letter::DuplicateConsonant(previous))) => {
+								*index != previous
+							},
+							(Letter::DuplicateConsonant(..), _) => true,
+							_ => false,
+						};
+					});
+				});
+			}),
+		};
+
+		for radical in Radical::all() {
+			let letter = query::radical_letter(script, radical);
+
+			alphabet.letters.insert(letter.clone());
+			alphabet.radicals.insert(letter);
+		}
+
+		for length in &[Length::L1, Length::L2] {
+			for page in Pages::new(*length) {
+				for word in page.into_iter().flatten().flatten().flatten() {
+					if let Some(letters) = query::letters(script, word) {
+						alphabet.letters.extend(letters.into_iter().filter(|letter| return !letter.is_empty()));
+					}
+				}
+			}
+		}
+
+		return alphabet;
+	}
+
+	/// Check if `string` starts with a letter of the [`Alphabet`].
+	pub(crate) fn starts_with_letter(&self, string: &str) -> bool {
+		return self.letters.iter().any(|letter| return string.starts_with(letter.as_str()));
+	}
+
+	/// Check if `string` ends with a letter of the [`Alphabet`].
+	pub(crate) fn ends_with_letter(&self, string: &str) -> bool {
+		return self.letters.iter().any(|letter| return string.ends_with(letter.as_str()));
+	}
+
+	/// Split `string` into letters, always taking the longest one, graphemes that aren't part of any letter are split off alone.
+	fn split<'a>(&self, string: &'a str) -> Vec<Split<'a>> {
+		let mut letters = Vec::new();
+		let mut string_left = string;
+		let mut position = 0;
+
+		while let Some(grapheme) = string_left.grapheme_nth(0) {
+			let text = self
+				.letters
+				.iter()
+				.filter(|letter| return string_left.starts_with(letter.as_str()))
+				.max_by_key(|letter| return letter.len())
+				.map_or(grapheme, |letter| return &string_left[..letter.len()]);
+
+			letters.push(Split { text, position });
+			position = position.padd(text.grapheme_len());
+			string_left = &string_left[text.len()..];
+		}
+
+		return letters;
+	}
+
+	/// Split `string` into letters, see [`Alphabet::split`].
+	fn texts<'a>(&self, string: &'a str) -> Vec<&'a str> {
+		return self.split(string).iter().map(|letter| return letter.text).collect();
+	}
+
+	/// Visit every letter sequence one edit away from `letters`, made of letters of the [`Alphabet`].
+	fn edits<'a>(&'a self, letters: &[&'a str], mut visit: impl FnMut(&[&'a str])) {
+		// every edit is built in the same buffer, most of them are thrown away right after
+		let mut edit = Vec::with_capacity(letters.len().padd(1));
+		let mut visit_with = |position: usize, letter: Option<&'a str>, rest: usize| {
+			edit.clear();
+			edit.extend_from_slice(&letters[..position]);
+			edit.extend(letter);
+			edit.extend_from_slice(&letters[rest..]);
+			visit(&edit);
+		};
+
+		for position in 0..=letters.len() {
+			for letter in &self.letters {
+				// insert
+				visit_with(position, Some(letter), position);
+
+				// replace
+				if position < letters.len() {
+					visit_with(position, Some(letter), position.padd(1));
+				}
+			}
+
+			// remove
+			if position < letters.len() {
+				visit_with(position, None, position.padd(1));
+			}
+		}
+	}
+
+	/// Get the position of the first radical that was already used before, a radical directly repeated is a duplicate consonant.
+	fn duplicate(&self, letters: &[&str]) -> Option<usize> {
+		return (0..letters.len()).find(|position| {
+			let letter = letters[*position];
+
+			return self.radicals.contains(letter)
+				&& letters[..*position].contains(&letter)
+				&& position.checked_sub(1).map(|previous| return letters[previous]) != Some(letter);
+		});
+	}
+
+	/// Get how many edits `letters` need at least before they can be read, every edit adds, removes or fixes one letter at most.
+	/// Words have [`structure::MAX_LETTERS`] letters at most, concepts have [`MAX_LENGTH`] radicals at most and use each only once.
+	fn edits_needed(&self, letters: &[&str]) -> usize {
+		// letters are only looked up if there are too many of them or some repeat
+		if letters.len() <= MAX_LENGTH
+			&& (0..letters.len()).all(|position| {
+				return letters[..position.saturating_sub(1)]
+					.iter()
+					.all(|previous| return previous != &letters[position]);
+			}) {
+			return 0;
+		}
+
+		let mut radicals: usize = 0;
+		let mut duplicates: usize = 0;
+
+		for (position, letter) in letters.iter().enumerate() {
+			if !self.radicals.contains(*letter) {
+				continue;
+			}
+
+			if !letters[..position].contains(letter) {
+				radicals = radicals.padd(1);
+			} else if !self.repeats_apart && position.checked_sub(1).map(|previous| return letters[previous]) != Some(*letter) {
+				duplicates = duplicates.padd(1);
+			}
+		}
+
+		return letters
+			.len()
+			.saturating_sub(structure::MAX_LETTERS)
+			.max(radicals.saturating_sub(MAX_LENGTH))
+			.max(duplicates);
+	}
+
+	/// Get radicals in order, leaving out duplicates.
+	fn radicals_in<'a>(&self, letters: &[&'a str]) -> Vec<&'a str> {
+		let mut radicals = Vec::new();
+
+		for letter in letters {
+			if self.radicals.contains(*letter) && !radicals.contains(letter) {
+				radicals.push(*letter);
+			}
+		}
+
+		return radicals;
+	}
+}
@@ -0,0 +1,167 @@
+//! Syllabification and stress.
+
+use crate::*;
+use std::ops::Range;
+
+/// Stress mark, written in front of the stressed syllable.
+const STRESS: char = 'ˈ';
+/// Separator between unstressed syllables.
+const SEPARATOR: char = '.';
+
+/// Rule that decides which syllable is stressed, see [`Config::set_stress`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stress {
+	/// First syllable.
+	Initial,
+	/// Second to last syllable, the only one in words with one syllable.
+	Penultimate,
+	/// Last syllable.
+	Final,
+	/// Last heavy syllable, one that is closed by a consonant or has a long vowel. The first syllable if none is heavy.
+	Heavy,
+}
+
+impl Default for Stress {
+	fn default() -> Self {
+		return Self::Penultimate;
+	}
+}
+
+/// Syllable of a [`Word`], return type from [`Word::syllables`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Syllable {
+	/// Byte range in the word.
+	range: Range<usize>,
+	/// Closed by a consonant or has a long vowel.
+	heavy: bool,
+	/// Stressed.
+	stressed: bool,
+}
+
+impl Syllable {
+	/// Get byte range in the string of the [`Word`].
+	#[must_use]
+	pub fn range(&self) -> Range<usize> {
+		return self.range.clone();
+	}
+
+	/// Get if the syllable is closed by a consonant or has a long vowel.
+	#[must_use]
+	pub const fn is_heavy(&self) -> bool {
+		return self.heavy;
+	}
+
+	/// Get if the syllable is stressed.
+	#[must_use]
+	pub const fn is_stressed(&self) -> bool {
+		return self.stressed;
+	}
+
+	/// Move syllable to `range`, used by scripts that write the same syllables differently.
+	pub(crate) fn with_range(self, range: Range<usize>) -> Self {
+		return Self { range, ..self };
+	}
+}
+
+/// Kind of a written segment, used to find syllables.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Unit {
+	/// Consonant, goes into the onset or coda.
+	Consonant,
+	/// Vowel, the nucleus of a syllable.
+	Vowel,
+	/// Duplicate, lengthens a vowel or doubles a consonant.
+	Duplicate,
+}
+
+/// Split written segments into [`Syllable`]s and stress them according to the current [`Config`].
+///
+/// Every vowel is a nucleus, a duplicate after a vowel makes it long. Of the consonants between two nuclei only the last one starts the next
+/// syllable, a doubled consonant starts it as a whole but makes the syllable before it heavy.
+pub(crate) fn syllabify(units: &[(Range<usize>, Unit)]) -> Vec<Syllable> {
+	// positions of nuclei, a duplicate after a vowel is part of it
+	let mut nuclei: Vec<Range<usize>> = Vec::new();
+
+	for (position, (_, unit)) in units.iter().enumerate() {
+		match unit {
+			Unit::Vowel => nuclei.push(position..position.padd(1)),
+			Unit::Duplicate => {
+				if let Some(nucleus) = nuclei.last_mut() {
+					if nucleus.end == position {
+						nucleus.end = position.padd(1);
+					}
+				}
+			},
+			Unit::Consonant => (),
+		}
+	}
+
+	// without a vowel the whole word is one syllable
+	if nuclei.is_empty() {
+		if let (Some((first, _)), Some((last, _))) = (units.first(), units.last()) {
+			return vec![Syllable {
+				range: first.start..last.end,
+				heavy: false,
+				stressed: false,
+			}];
+		}
+
+		return Vec::new();
+	}
+
+	let mut syllables = Vec::new();
+	let mut start = 0;
+
+	for (index, nucleus) in nuclei.iter().enumerate() {
+		let end = if let Some(next) = nuclei.get(index.padd(1)) {
+			// the last consonant before the next nucleus starts the next syllable, together with its duplicate
+			(nucleus.end..next.start)
+				.rev()
+				.find(|position| return units[*position].1 == Unit::Consonant)
+				.unwrap_or(next.start)
+		} else {
+			units.len()
+		};
+		// a doubled consonant starting the next syllable closes this one
+		let geminate = units[end..]
+			.iter()
+			.take_while(|(_, unit)| return *unit != Unit::Vowel)
+			.any(|(_, unit)| return *unit == Unit::Duplicate);
+
+		syllables.push(Syllable {
+			range: units[start].0.start..units[end.psub(1)].0.end,
+			heavy: end > nucleus.end || nucleus.len() > 1 || (end < units.len() && geminate),
+			stressed: false,
+		});
+
+		start = end;
+	}
+
+	let stressed = match Config::current().stress() {
+		Stress::Initial => 0,
+		Stress::Penultimate => syllables.len().saturating_sub(2),
+		Stress::Final => syllables.len().psub(1),
+		Stress::Heavy => syllables.iter().rposition(|syllable| return syllable.heavy).unwrap_or(0),
+	};
+
+	syllables[stressed].stressed = true;
+
+	return syllables;
+}
+
+/// Write `string` with its [`Syllable`]s separated and the stress marked, words with one syllable aren't marked.
+pub(crate) fn syllabified(string: &str, syllables: &[Syllable]) -> String {
+	let mut syllabified = String::with_capacity(string.len().padd(syllables.len().pmul(STRESS.len_utf8())));
+
+	for (position, syllable) in syllables.iter().enumerate() {
+		if syllables.len() > 1 && syllable.stressed {
+			syllabified.push(STRESS);
+		} else if position > 0 {
+			syllabified.push(SEPARATOR);
+		}
+
+		syllabified.push_str(&string[syllable.range()]);
+	}
+
+	return syllabified;
+}
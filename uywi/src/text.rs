@@ -0,0 +1,187 @@
+//! Text handling: tokenizing and transliterating whole texts.
+
+use crate::{suggestion::Alphabet, *};
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Kind of a [`Token`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenKind {
+	/// [`Concept`] or [`Word`] that could be read by the [`Script`].
+	Word(Parsed),
+	/// Word that couldn't be read by the [`Script`].
+	Untranslatable,
+	/// Whitespace.
+	Whitespace,
+	/// Punctuation in front or after a word.
+	Punctuation,
+}
+
+/// Part of a text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token<'a> {
+	/// Kind of token.
+	kind: TokenKind,
+	/// Part of the text.
+	string: &'a str,
+	/// Byte range in the text.
+	range: Range<usize>,
+}
+
+impl<'a> Token<'a> {
+	/// Get kind.
+	#[must_use]
+	pub const fn kind(&self) -> TokenKind {
+		return self.kind;
+	}
+
+	/// Get string.
+	#[must_use]
+	pub const fn as_str(&self) -> &'a str {
+		return self.string;
+	}
+
+	/// Get byte range in the text.
+	#[must_use]
+	pub fn range(&self) -> Range<usize> {
+		return self.range.clone();
+	}
+}
+
+/// Return type from [`transliterate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transliteration {
+	/// Transliterated text.
+	text: String,
+	/// Byte ranges of untranslatable tokens in the original text.
+	untranslatable: Vec<Range<usize>>,
+}
+
+impl Transliteration {
+	/// Get transliterated text, untranslatable tokens are kept as they are.
+	#[must_use]
+	pub fn text(&self) -> &str {
+		return &self.text;
+	}
+
+	/// Get byte ranges of untranslatable tokens in the original text.
+	#[must_use]
+	pub fn untranslatable(&self) -> &[Range<usize>] {
+		return &self.untranslatable;
+	}
+}
+
+/// Split `text` into words, whitespace and punctuation, words are read with `script`.
+///
+/// Graphemes that aren't letters or numbers are punctuation at the start or end of a word. If the [`Script`] uses them as letters, e.g. `?`
+/// in [`Script::UywiChiffre`], they are only punctuation if the word can't be read with them.
+#[must_use]
+pub fn tokenize(text: &str, script: Script) -> Vec<Token<'_>> {
+	let alphabet = Alphabet::get(script);
+	let mut tokens = Vec::new();
+	// start of the current run of whitespace or non-whitespace
+	let mut start = 0;
+
+	for (index, char) in text.char_indices() {
+		let next = index.padd(char.len_utf8());
+
+		// split at the border between whitespace and non-whitespace
+		if next == text.len() || text[next..].starts_with(char::is_whitespace) != char.is_whitespace() {
+			if char.is_whitespace() {
+				tokens.push(Token {
+					kind: TokenKind::Whitespace,
+					string: &text[start..next],
+					range: start..next,
+				});
+			} else {
+				tokenize_word(text, start..next, script, &alphabet, &mut tokens);
+			}
+
+			start = next;
+		}
+	}
+
+	return tokens;
+}
+
+/// Transliterate `text` from one [`Script`] to another, keeping whitespace, punctuation and untranslatable words.
+#[must_use]
+pub fn transliterate(text: &str, from: Script, to: Script) -> Transliteration {
+	let mut transliteration = Transliteration {
+		text: String::with_capacity(text.len()),
+		untranslatable: Vec::new(),
+	};
+
+	for token in tokenize(text, from) {
+		match token.kind {
+			TokenKind::Word(parsed) => transliteration.text.push_str(&parsed.to_string(to)),
+			TokenKind::Untranslatable => {
+				transliteration.text.push_str(token.string);
+				transliteration.untranslatable.push(token.range);
+			},
+			TokenKind::Whitespace | TokenKind::Punctuation => transliteration.text.push_str(token.string),
+		}
+	}
+
+	return transliteration;
+}
+
+/// Split a run of non-whitespace into punctuation and a word.
+fn tokenize_word<'a>(text: &'a str, range: Range<usize>, script: Script, alphabet: &Alphabet, tokens: &mut Vec<Token<'a>>) {
+	let string = &text[range.clone()];
+	// punctuation that can't be a letter of the script is peeled off right away
+	let word = peel(string, Some(alphabet));
+
+	let (word, kind) = match script.from_str(&string[word.clone()]) {
+		Ok(parsed) => (word, TokenKind::Word(parsed)),
+		// letters of the script at the start or end are only punctuation if the word can't be read with them
+		Err(_) => {
+			let peeled = peel(string, None);
+
+			match script.from_str(&string[peeled.clone()]) {
+				Ok(parsed) if peeled != word => (peeled, TokenKind::Word(parsed)),
+				_ => (peeled, TokenKind::Untranslatable),
+			}
+		},
+	};
+	let word = range.start.padd(word.start)..range.start.padd(word.end);
+
+	for (range, kind) in &[
+		(range.start..word.start, TokenKind::Punctuation),
+		(word.clone(), kind),
+		(word.end..range.end, TokenKind::Punctuation),
+	] {
+		if !range.is_empty() {
+			tokens.push(Token {
+				kind: *kind,
+				string: &text[range.clone()],
+				range: range.clone(),
+			});
+		}
+	}
+}
+
+/// Get byte range of `string` without punctuation at the start and end, punctuation that starts or ends a letter of `alphabet` is kept.
+fn peel(string: &str, alphabet: Option<&Alphabet>) -> Range<usize> {
+	let is_punctuation = |grapheme: &str| return grapheme.chars().all(|char| return !char.is_alphanumeric());
+	let mut start = 0;
+	let mut end = string.len();
+
+	while let Some(grapheme) = string[start..end].graphemes(true).next() {
+		if !is_punctuation(grapheme) || alphabet.map_or(false, |alphabet| return alphabet.starts_with_letter(&string[start..end])) {
+			break;
+		}
+
+		start = start.padd(grapheme.len());
+	}
+
+	while let Some(grapheme) = string[start..end].graphemes(true).next_back() {
+		if !is_punctuation(grapheme) || alphabet.map_or(false, |alphabet| return alphabet.ends_with_letter(&string[start..end])) {
+			break;
+		}
+
+		end = end.psub(grapheme.len());
+	}
+
+	return start..end;
+}
@@ -1,6 +1,10 @@
 //! UYWI words.
 
-use crate::*;
+use crate::{structure, *};
+use arrayvec::ArrayVec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 
 /// Words, only used for iteration.
 #[derive(Clone, Debug)]
@@ -43,7 +47,13 @@ impl Iterator for Words {
 }
 
 /// A word.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Words are ordered by their [`Concept`] first and [`Word::index`] second, use [`Collation`] for alphabetical order.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+	feature = "serde",
+	derive(Deserialize, Serialize),
+	serde(into = "(Length, usize, u8, u8)", try_from = "(Length, usize, u8, u8)")
+)]
 pub struct Word {
 	/// [`Concept`] to build from.
 	concept: Concept,
@@ -72,12 +82,143 @@ impl Word {
 		};
 	}
 
+	/// Build [`Word`] from [`Concept`], stem index and form index.
+	/// # Errors
+	/// [`Error::StemIndexInvalid`] if `stem_index` is higher then the number of stems of the given [`Concept`]
+	///
+	/// [`Error::FormIndexInvalid`] if `form_index` is higher then the number of forms of the given [`Concept`]
+	pub fn from_concept(concept: Concept, stem_index: u8, form_index: u8) -> Result<Self> {
+		if usize::from(stem_index) >= concept.length().stems_per_concept() {
+			return Err(Error::StemIndexInvalid);
+		}
+
+		if usize::from(form_index) >= concept.length().words_per_stem() {
+			return Err(Error::FormIndexInvalid);
+		}
+
+		return Ok(Self::new(concept, stem_index, form_index));
+	}
+
+	/// Build [`Word`] from word index.
+	/// # Errors
+	/// [`Error::WordIndexInvalid`] if `index` is higher then the number of words of the given [`Length`]
+	pub fn from_index(index: usize, length: Length) -> Result<Self> {
+		// check for valid word index
+		if index >= length.num_of_words() {
+			return Err(Error::WordIndexInvalid);
+		}
+
+		// `ConceptIndex * WordsPerConcept + StemIndex * WordsPerStem + FormIndex`
+		let words_per_concept = length.stems_per_concept().pmul(length.words_per_stem());
+		let concept = Concept::from_index(index.pdiv(words_per_concept), length)?;
+		let index_left = index.psub(concept.index().pmul(words_per_concept));
+		let stem_index = index_left.pdiv(length.words_per_stem());
+		let form_index = index_left.psub(stem_index.pmul(length.words_per_stem()));
+
+		return Ok(Self::new(concept, stem_index.pinto(), form_index.pinto()));
+	}
+
+	/// Build [`Word`] from index string, e.g. `1234.3.2` for the second form of the third stem of concept `1234`.
+	/// # Errors
+	/// [`Error::WordStringInvalid`] if `index` isn't made of three numbers separated by `.`
+	///
+	/// [`Error::WordStringNull`] if any number in `index` is 0
+	///
+	/// [`Error::ConceptIndexInvalid`] if the concept is higher then the number of concepts of the given [`Length`]
+	///
+	/// [`Error::StemIndexInvalid`] if the stem is higher then the number of stems of the given [`Length`]
+	///
+	/// [`Error::FormIndexInvalid`] if the form is higher then the number of forms of the given [`Length`]
+	pub fn from_index_str(index: &str, length: Length) -> Result<Self> {
+		let mut indexes = ArrayVec::<[usize; 3]>::new();
+
+		for index in index.split('.') {
+			let index: usize = index.parse().map_err(|_| return Error::WordStringInvalid)?;
+			// in string form every index is always `+ 1`
+			let index = index.checked_sub(1).ok_or(Error::WordStringNull)?;
+
+			indexes.try_push(index).map_err(|_| return Error::WordStringInvalid)?;
+		}
+
+		if let [concept_index, stem_index, form_index] = indexes.as_slice() {
+			let concept = Concept::from_index(*concept_index, length)?;
+			let stem_index = u8::try_from(*stem_index).map_err(|_| return Error::StemIndexInvalid)?;
+			let form_index = u8::try_from(*form_index).map_err(|_| return Error::FormIndexInvalid)?;
+
+			return Self::from_concept(concept, stem_index, form_index);
+		} else {
+			return Err(Error::WordStringInvalid);
+		}
+	}
+
+	/// Get index.
+	#[must_use]
+	pub fn index(self) -> usize {
+		let length = self.concept.length();
+
+		// `ConceptIndex * WordsPerConcept + StemIndex * WordsPerStem + FormIndex`
+		return self
+			.concept
+			.index()
+			.pmul(length.stems_per_concept())
+			.padd(usize::from(self.stem_index))
+			.pmul(length.words_per_stem())
+			.padd(usize::from(self.form_index));
+	}
+
+	/// Get index as string, e.g. `1234.3.2` for the second form of the third stem of concept `1234`.
+	#[must_use]
+	pub fn index_as_string(self) -> String {
+		// in string form every index is always `+ 1`
+		return format!(
+			"{}.{}.{}",
+			self.concept.index_as_string(),
+			self.stem_index.padd(1),
+			self.form_index.padd(1)
+		);
+	}
+
 	/// Get word as [`String`].
 	#[must_use]
 	pub fn to_string(self, script: Script) -> String {
 		return script.word(self).to_string();
 	}
 
+	/// Get the segments of the word after each assimilation the [`Script`] applies, with the positions each one changed.
+	/// [`None`] if the [`Script`] doesn't apply assimilations, e.g. [`Script::UywiChiffre`].
+	#[must_use]
+	pub fn derivation(self, script: Script) -> Option<Derivation> {
+		return script.phonology().map(|phonology| return phonology.derivation(self));
+	}
+
+	/// Get [`Syllable`]s of the word written in `script`, the stressed one is chosen by the [`Stress`] rule of the current [`Config`].
+	/// [`None`] if the [`Script`] can't split words into syllables, e.g. [`Script::UywiChiffre`].
+	#[must_use]
+	pub fn syllables(self, script: Script) -> Option<Vec<Syllable>> {
+		return script.phonology().map(|phonology| return phonology.syllables(self));
+	}
+
+	/// Get word as [`String`] with syllables separated by `.` and `ˈ` in front of the stressed syllable, e.g. `ˈxa.ħːɑ̃`.
+	/// Words with one syllable aren't marked. [`None`] if the [`Script`] can't split words into syllables, see [`Word::syllables`].
+	#[must_use]
+	pub fn to_string_syllabified(self, script: Script) -> Option<String> {
+		let syllables = self.syllables(script)?;
+
+		return Some(syllable::syllabified(&self.to_string(script), &syllables));
+	}
+
+	/// Get stem structure.
+	#[must_use]
+	pub fn structure(self) -> ArrayVec<[Letter; 8]> {
+		return structure::structures(self.concept.length(), self.stem_index);
+	}
+
+	/// Get [`Form`] from the current [`Config`].
+	#[must_use]
+	pub fn form(self) -> Form {
+		return Config::current().forms(self.concept.length())[usize::from(self.form_index)];
+	}
+
 	/// Get [`Concept`].
 	pub const fn concept(self) -> Concept {
 		return self.concept;
@@ -93,3 +234,19 @@ impl Word {
 		return self.form_index;
 	}
 }
+
+#[cfg(feature = "serde")]
+impl From<Word> for (Length, usize, u8, u8) {
+	fn from(word: Word) -> Self {
+		return (word.concept.length(), word.concept.index(), word.stem_index, word.form_index);
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<(Length, usize, u8, u8)> for Word {
+	type Error = Error;
+
+	fn try_from((length, concept_index, stem_index, form_index): (Length, usize, u8, u8)) -> Result<Self> {
+		return Self::from_concept(Concept::from_index(concept_index, length)?, stem_index, form_index);
+	}
+}
@@ -0,0 +1,46 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Concept, Length, Script};
+
+#[test]
+fn ambiguity() {
+	// dark and light `g` look the same, the vowel is light either way because of `ç`
+	let script = Script::IpaPeter;
+	let parsed = script.from_str("çeg").expect("failed to create word");
+	let all = script.parse_all("çeg").expect("failed to create word");
+
+	assert!(parsed.is_ambiguous(), "word should be ambiguous");
+	assert!(all.len() == 2, "word should have two meanings");
+	assert!(parsed.concept_or_word() == all[0], "first meaning should be returned");
+	assert!(
+		all.iter().all(|concept_or_word| return concept_or_word.to_string(script) == "çeg"),
+		"meanings should have the same string"
+	);
+
+	let script = Script::UywiChiffre;
+	let word = Concept::from_index(0, Length::L4)
+		.expect("failed to create concept")
+		.into_iter()
+		.flatten()
+		.last()
+		.expect("failed to create word")
+		.to_string(script);
+	let parsed = script.from_str(&word).expect("failed to create word");
+
+	assert!(!parsed.is_ambiguous(), "word shouldn't be ambiguous");
+	assert!(
+		script.parse_all(&word).expect("failed to create word") == vec![parsed.concept_or_word()],
+		"word should have one meaning"
+	);
+}
@@ -0,0 +1,78 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use std::{
+	cmp::Ordering,
+	collections::{BTreeSet, HashSet},
+};
+use uywi::{Collation, Concept, Length, Pages, Script};
+
+#[test]
+fn collation() {
+	let concepts: Vec<_> = Pages::new(Length::L2).flat_map(|page| return page.into_iter().flatten()).collect();
+	let words: Vec<_> = concepts.iter().flat_map(|concept| return concept.into_iter().flatten()).collect();
+
+	assert!(
+		concepts.iter().copied().collect::<HashSet<_>>().len() == concepts.len(),
+		"concepts should hash differently"
+	);
+	assert!(
+		words.iter().copied().collect::<BTreeSet<_>>().into_iter().eq(words.iter().copied()),
+		"words should be ordered by index"
+	);
+	assert!(
+		concepts.windows(2).all(|pair| return pair[0] < pair[1]),
+		"concepts should be ordered by index"
+	);
+	assert!(
+		Concept::from_index(Length::L1.num_of_concepts() - 1, Length::L1).expect("failed to create concept") < concepts[0],
+		"concepts should be ordered by length"
+	);
+
+	// `h` comes before `2` in UYWI Chiffre and `æ` right after `a` in IPA
+	assert!(
+		Collation::new(Script::UywiChiffre).compare("h", "2") == Ordering::Less,
+		"radical order is wrong"
+	);
+	assert!(Collation::new(Script::IpaPeter).compare("æ", "b") == Ordering::Less, "IPA order is wrong");
+	assert!(
+		Collation::new(Script::IpaPeter).compare("a", "aː") == Ordering::Less,
+		"IPA order is wrong"
+	);
+
+	for script in &[Script::UywiChiffre, Script::IpaPeter, Script::Latin] {
+		let collation = Collation::new(*script);
+		let mut sorted = words.clone();
+		collation.sort_words(&mut sorted);
+
+		assert!(
+			sorted
+				.windows(2)
+				.all(|pair| return collation.compare(&pair[0].to_string(*script), &pair[1].to_string(*script)) != Ordering::Greater),
+			"words aren't sorted"
+		);
+	}
+
+	// X-SAMPA is sorted like the IPA it's written from
+	let mut ipa = words.clone();
+	let mut x_sampa = words;
+	Collation::new(Script::IpaPeter).sort_words(&mut ipa);
+	Collation::new(Script::XSampa).sort_words(&mut x_sampa);
+
+	assert!(ipa == x_sampa, "X-SAMPA order is wrong");
+
+	let mut sorted = concepts;
+	Collation::new(Script::UywiChiffre).sort_concepts(&mut sorted);
+
+	assert!(sorted[0].to_string(Script::UywiChiffre) == "?Y", "first concept is wrong");
+}
@@ -0,0 +1,48 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Collisions, Length, Script};
+
+#[test]
+fn collisions() {
+	for script in &[Script::IpaPeter, Script::UywiChiffre] {
+		let collisions = Collisions::new(*script, Length::L2);
+
+		assert!(collisions.num_of_words() == Length::L2.num_of_words(), "number of words is wrong");
+		assert!(
+			collisions.num_of_strings() + collisions.num_of_colliding_words() - collisions.collisions().len() == collisions.num_of_words(),
+			"counts don't add up"
+		);
+
+		for collision in collisions.collisions() {
+			assert!(collision.words().len() > 1, "collision should have more then one word");
+			assert!(
+				collision.words().iter().all(|word| return word.to_string(*script) == collision.as_str()),
+				"words should render the same"
+			);
+		}
+
+		assert!(collisions.to_string().contains("Colliding strings"), "report is wrong");
+	}
+
+	let collisions = Collisions::new(Script::IpaPeter, Length::L2);
+
+	assert!(!collisions.collisions().is_empty(), "IPA should have homophones");
+	assert!(
+		collisions
+			.collisions()
+			.windows(2)
+			.all(|pair| return pair[0].words().len() >= pair[1].words().len()),
+		"collisions should be ordered by size"
+	);
+}
@@ -0,0 +1,86 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use std::collections::BTreeSet;
+use unicode_segmentation::UnicodeSegmentation;
+use uywi::{Length, Pages, Script};
+
+#[test]
+fn complete() {
+	for (script, length, prefixes) in &[
+		(Script::UywiChiffre, Length::L2, &["", "k", "ko", "koh", "kıh", "?", "ı"][..]),
+		(Script::UywiChiffre, Length::L3, &["", "Hı", "Hıwo"][..]),
+		(Script::Latin, Length::L2, &["", "s", "sh", "sha", "shh", "h"][..]),
+		(Script::Latin, Length::L3, &["bh", "cc"][..]),
+		(Script::IpaPeter, Length::L2, &["", "x", "xa"][..]),
+		(Script::IpaPeter, Length::L3, &["", "ʔa", "kʰaɣ"][..]),
+		(Script::XSampa, Length::L2, &["", "x", "d_Z"][..]),
+	] {
+		let strings: Vec<_> = Pages::new(*length)
+			.flat_map(|page| return page.into_iter().flatten())
+			.flat_map(|concept| {
+				return std::iter::once(concept.to_string(*script)).chain(concept.into_iter().flatten().map(|word| return word.to_string(*script)));
+			})
+			.collect();
+
+		for prefix in *prefixes {
+			let completions = script.complete(prefix, *length, 10);
+			let matching: Vec<_> = strings.iter().filter(|string| return string.starts_with(prefix)).collect();
+			let next: BTreeSet<_> = matching
+				.iter()
+				.filter_map(|string| return string[prefix.len()..].graphemes(true).next().map(str::to_owned))
+				.collect();
+
+			assert!(completions.next().iter().cloned().eq(next), "next graphemes are wrong");
+			assert!(
+				completions.completions().len() == matching.len().min(10),
+				"number of completions is wrong"
+			);
+			assert!(
+				completions.completions().iter().all(|completion| {
+					let string = completion.concept_or_word().to_string(*script);
+
+					return string.starts_with(prefix) && string.graphemes(true).count() == prefix.graphemes(true).count() + completion.remaining();
+				}),
+				"completion is wrong"
+			);
+			assert!(
+				completions
+					.completions()
+					.windows(2)
+					.all(|pair| return pair[0].remaining() <= pair[1].remaining()),
+				"completions should be ordered by graphemes left"
+			);
+			let mut remaining: Vec<_> = matching
+				.iter()
+				.map(|string| return string.graphemes(true).count() - prefix.graphemes(true).count())
+				.collect();
+			remaining.sort_unstable();
+			remaining.truncate(10);
+
+			assert!(
+				completions
+					.completions()
+					.iter()
+					.map(|completion| return completion.remaining())
+					.eq(remaining),
+				"nearest completions are missing"
+			);
+		}
+	}
+
+	assert!(
+		Script::UywiChiffre.complete("ıı", Length::L2, 10).next().is_empty(),
+		"nothing should follow"
+	);
+}
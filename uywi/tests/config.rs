@@ -0,0 +1,220 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Concept, Config, Error, Form, FormVowel, Length, Letter, Radical, Rules, Script, StemTemplate, Stress, Vowel};
+
+#[test]
+fn template() {
+	let template: StemTemplate = "c0 v0 c1 xc1 xn0".parse().expect("failed to parse template");
+
+	assert!(
+		template.letters()
+			== [
+				Letter::Consonant(0),
+				Letter::Vowel(Vowel::First),
+				Letter::Consonant(1),
+				Letter::DuplicateConsonant(1),
+				Letter::Nasal(Vowel::First),
+			],
+		"template letters are wrong"
+	);
+	assert!(template.to_string() == "c0 v0 c1 xc1 xn0", "template string is wrong");
+
+	assert!(
+		matches!("".parse::<StemTemplate>(), Err(Error::TemplateEmpty)),
+		"template should be empty"
+	);
+	assert!(
+		matches!("c0 v2 c1".parse::<StemTemplate>(), Err(Error::TemplateLetterInvalid(1))),
+		"vowel should be invalid"
+	);
+	assert!(
+		matches!("c0 y0 c1".parse::<StemTemplate>(), Err(Error::TemplateLetterInvalid(1))),
+		"letter should be invalid"
+	);
+	assert!(
+		matches!("c0 v0 xc1 c1".parse::<StemTemplate>(), Err(Error::TemplateDuplicateInvalid(2))),
+		"duplicate should be invalid"
+	);
+	assert!(
+		matches!("c0 v0 c1 v1 c2 v0 c3 v1 c4".parse::<StemTemplate>(), Err(Error::TemplateTooLong)),
+		"template should be too long"
+	);
+}
+
+#[test]
+fn form() {
+	let form: Form = "a e".parse().expect("failed to parse form");
+
+	assert!(form.get(Vowel::First) == FormVowel::A, "form vowel is wrong");
+	assert!(form.get(Vowel::Last) == FormVowel::E, "form vowel is wrong");
+	assert!(form.to_string() == "a e", "form string is wrong");
+	assert!(
+		matches!("a o".parse::<Form>(), Err(Error::FormVowelInvalid)),
+		"form vowel should be invalid"
+	);
+	assert!(matches!("a e i".parse::<Form>(), Err(Error::FormVowelInvalid)), "form should be invalid");
+}
+
+// a `Config` can only be installed once, so everything that needs it installed is tested in one test
+#[test]
+fn config() {
+	let mut config = Config::new();
+	let template = |string: &str| return string.parse::<StemTemplate>().expect("failed to parse template");
+
+	assert!(
+		matches!(
+			config.set_templates(Length::L2, vec![template("c0 v0 c2")]),
+			Err(Error::TemplateConsonantMissing(1))
+		),
+		"consonant should be missing"
+	);
+	assert!(
+		matches!(
+			config.set_templates(Length::L2, vec![template("c0 v0 c1 c1")]),
+			Err(Error::TemplateConsonantDuplicate(1))
+		),
+		"consonant should be duplicate"
+	);
+	assert!(
+		matches!(
+			config.set_templates(Length::L2, vec![template("c0 v0 c1 c2")]),
+			Err(Error::TemplateConsonantInvalid(3))
+		),
+		"consonant should be invalid"
+	);
+	assert!(
+		matches!(config.set_templates(Length::L2, Vec::new()), Err(Error::TemplatesEmpty)),
+		"templates should be empty"
+	);
+
+	config
+		.set_templates(Length::L2, vec![template("c0 v0 c1"), template("v0 c0 v1 c1")])
+		.expect("failed to set templates");
+
+	assert!(
+		matches!(config.set_forms(Length::L2, Vec::new()), Err(Error::FormsEmpty)),
+		"forms should be empty"
+	);
+
+	config
+		.set_forms(
+			Length::L2,
+			vec![
+				Form::new(FormVowel::A, FormVowel::A),
+				Form::new(FormVowel::E, FormVowel::E),
+				Form::new(FormVowel::I, FormVowel::I),
+			],
+		)
+		.expect("failed to set forms");
+
+	assert!(
+		matches!(config.set_radicals(vec![0, 1, 2, 3]), Err(Error::RadicalsTooFew)),
+		"radicals should be too few"
+	);
+	assert!(
+		matches!(config.set_radicals(vec![0, 1, 2, 3, 4, 1]), Err(Error::RadicalsDuplicate)),
+		"radicals should be duplicate"
+	);
+	assert!(
+		matches!(config.set_radicals((0..45).collect()), Err(Error::RadicalsTooMany)),
+		"radicals should be too many"
+	);
+	assert!(
+		matches!(config.set_radicals(vec![0, 1, 2, 3, 44]), Err(Error::RadicalsInvalid)),
+		"radicals should be invalid"
+	);
+
+	// beginner subset of 30 radicals without `w`
+	config
+		.set_radicals((0..31).filter(|id| return *id != 2).collect())
+		.expect("failed to set radicals");
+
+	// keep `ʔ` at the beginning of words
+	config.set_ipa_rules(Rules::new(
+		Rules::default()
+			.rules()
+			.iter()
+			.filter(|rule| return rule.name() != "beginning")
+			.cloned()
+			.collect(),
+	));
+	config.set_stress(Stress::Final);
+	config.install().expect("failed to install config");
+
+	assert!(
+		matches!(Config::new().install(), Err(Error::ConfigInstalled)),
+		"config should be installed already"
+	);
+
+	assert!(Length::L2.num_of_concepts() == 30 * 29, "number of concepts is wrong");
+	assert!(Length::L3.num_of_pages() == 30, "number of pages is wrong");
+	assert!(
+		Radical::from_id(3).expect("failed to create radical").index() == 2,
+		"radical index is wrong"
+	);
+	assert!(
+		Concept::from_index(0, Length::L2)
+			.expect("failed to create concept")
+			.to_string(Script::UywiChiffre)
+			== "?h",
+		"last radical should start at `h`"
+	);
+	assert!(
+		matches!(Radical::from_id(2), Err(Error::ConceptRadicalInvalid)),
+		"radical should be invalid"
+	);
+	assert!(
+		matches!(Script::UywiChiffre.from_concept("?w"), Err(Error::ConceptRadicalInvalid)),
+		"concept should be invalid"
+	);
+	assert!(
+		Concept::from_index(Length::L3.num_of_concepts() - 1, Length::L3)
+			.expect("failed to create concept")
+			.radicals()
+			.iter()
+			.all(|radical| return radical.id() < 31 && radical.id() != 2),
+		"concept radicals are wrong"
+	);
+
+	assert!(Length::L2.stems_per_concept() == 2, "number of stems is wrong");
+	assert!(Length::L2.words_per_stem() == 3, "number of forms is wrong");
+
+	let concept = Concept::from_radicals(&[
+		Radical::from_id(0).expect("failed to create radical"),
+		Radical::from_id(3).expect("failed to create radical"),
+	])
+	.expect("failed to create concept");
+	let words: Vec<_> = concept.into_iter().flatten().collect();
+
+	assert!(words.len() == 6, "number of words is wrong");
+	assert!(concept.to_string(Script::UywiChiffre) == "?h", "concept string is wrong");
+	assert!(words[3].to_string(Script::UywiChiffre) == "o?oh", "word string is wrong");
+	assert!(words[1].to_string(Script::IpaPeter) == "ʔe", "word string is wrong");
+	assert!(words[2].to_string(Script::IpaPeter) == "ʔi", "word string is wrong");
+	assert!(
+		words[3].to_string_syllabified(Script::IpaPeter).as_deref() == Some("aˈʔa"),
+		"syllabified word is wrong"
+	);
+
+	for script in &[Script::UywiChiffre, Script::IpaPeter] {
+		for word in &words {
+			let string = word.to_string(*script);
+
+			assert!(
+				script.from_str(&string).expect("failed to create word").to_string(*script) == string,
+				"word string is wrong"
+			);
+		}
+	}
+}
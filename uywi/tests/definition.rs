@@ -0,0 +1,145 @@
+#![cfg(feature = "definition")]
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Action, Error, Extension, Feature, Length, Pages, Radical, Rules, Script, ScriptDefinition, ScriptRegistry, Side};
+
+/// Definition that should behave exactly like [`Script::UywiChiffre`].
+const UYWI_CHIFFRE: &str = include_str!("scripts/uywi_chiffre.toml");
+/// Rules that should behave exactly like the built-in rules of [`Script::IpaPeter`].
+const IPA_PETER_RULES: &str = include_str!("scripts/ipa_peter_rules.toml");
+
+#[test]
+fn uywi_chiffre() {
+	let definition = ScriptDefinition::from_toml(UYWI_CHIFFRE).expect("failed to load definition");
+	let script = ScriptRegistry::new()
+		.register(Box::leak(Box::new(definition)))
+		.expect("failed to register script");
+
+	for length in &[Length::L2, Length::L3, Length::L4] {
+		for page in Pages::new(*length).step_by(97) {
+			for concept in page.into_iter().flatten().step_by(101) {
+				let string = concept.to_string(script);
+
+				assert!(string == concept.to_string(Script::UywiChiffre), "concept string is wrong");
+				assert!(
+					concept == script.from_concept(&string).expect("failed to create concept"),
+					"concept string is wrong"
+				);
+
+				for word in concept.into_iter().flatten() {
+					let string = word.to_string(script);
+
+					assert!(string == word.to_string(Script::UywiChiffre), "word string is wrong");
+					assert!(
+						script.parse_all(&string).expect("failed to create word")
+							== Script::UywiChiffre.parse_all(&string).expect("failed to create word"),
+						"word string is wrong"
+					);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn json() {
+	let definition = r#"{
+		"name": "Marked",
+		"radicals": [
+			"?", { "glyph": "Y", "mark": "'" }, "w", "h", "2", "H", "K", "k", "X", "x", "8", "4", "G", "g", "j", "7", "3", "Q", "c", "9", "S", "s",
+			"Z", "z", "D", "d", "T", "t", "P", "0", "B", "6", "V", "f", "p", "b", "m", "n", "O", "R", "r", "1", "L", "l"
+		],
+		"vowels": { "a": "a", "e": "e", "i": "i", "u": "u" }
+	}"#;
+	let script = ScriptDefinition::from_json(definition).expect("failed to load definition");
+	let concept = script.from_concept("Y'w").expect("failed to create concept");
+	let word = concept.into_iter().flatten().next().expect("failed to create word");
+
+	assert!(script.name() == "Marked", "script name is wrong");
+	assert!(
+		concept.radicals().as_slice() == [Radical::from_index(1), Radical::from_index(2)],
+		"concept is wrong"
+	);
+	assert!(script.concept(concept).as_str() == "Y'w", "concept string is wrong");
+	assert!(script.word(word).as_str() == "Yaw", "word string is wrong");
+}
+
+#[test]
+fn invalid() {
+	let duplicate = UYWI_CHIFFRE.replace(r#""l","#, r#""L","#).replace(r#""L","#, r#""l","#);
+	// `12` can be read as `1` and `2` too
+	let ambiguous = UYWI_CHIFFRE.replace(r#""L""#, r#""12""#);
+	let missing = UYWI_CHIFFRE.replace(r#""?", "#, "");
+	let vowels = UYWI_CHIFFRE.replace(r#"u = "ı""#, r#"o = "ı""#);
+
+	assert!(
+		matches!(ScriptDefinition::from_toml("name = 1"), Err(Error::ScriptDefinitionInvalid)),
+		"definition should be invalid"
+	);
+	assert!(
+		matches!(ScriptDefinition::from_toml(&duplicate), Err(Error::ScriptRadicalDuplicate)),
+		"radicals should be duplicate"
+	);
+	assert!(
+		matches!(ScriptDefinition::from_toml(&ambiguous), Err(Error::ScriptRadicalDuplicate)),
+		"radicals should be ambiguous"
+	);
+	assert!(
+		matches!(ScriptDefinition::from_toml(&missing), Err(Error::ScriptRadicalsInvalid)),
+		"radicals should be invalid"
+	);
+	assert!(
+		matches!(ScriptDefinition::from_toml(&vowels), Err(Error::ScriptFormsInvalid)),
+		"vowels should be invalid"
+	);
+
+	let definition = ScriptDefinition::from_toml(UYWI_CHIFFRE).expect("failed to load definition");
+
+	// splitting stops after the longest concept
+	assert!(
+		matches!(definition.from_concept(&"?".repeat(100_000)), Err(Error::ConceptRadicalInvalid)),
+		"concept should be invalid"
+	);
+}
+
+#[test]
+fn rules() {
+	let rules = Rules::from_toml(IPA_PETER_RULES).expect("failed to load rules");
+
+	assert!(rules == Rules::default(), "rules should be the built-in ones");
+
+	let rule = &rules.rules()[0];
+	let context = &rule.contexts()[0];
+
+	assert!(rule.name() == "dark", "rule name is wrong");
+	assert!(rule.action() == Action::Dark, "rule action is wrong");
+	assert!(rule.target().features() == [(Feature::Vowel, true)], "rule target is wrong");
+	assert!(context.side() == Side::Around, "context side is wrong");
+	assert!(context.pattern().to_string() == "consonant dark", "context pattern is wrong");
+	assert!(
+		context.skip().map(ToString::to_string).as_deref() == Some("duplicate"),
+		"context skip is wrong"
+	);
+
+	let feature = IPA_PETER_RULES.replace(r#"match = "consonant dark""#, r#"match = "consonant dull""#);
+	let moving = IPA_PETER_RULES.replace(r#"match = "vowel", skip = "removed""#, r##"match = "#""##);
+	let action = IPA_PETER_RULES.replace(r#"action = "rounding""#, r#"action = "round""#);
+
+	assert!(
+		matches!(Rules::from_toml(&feature), Err(Error::FeatureInvalid)),
+		"feature should be invalid"
+	);
+	assert!(matches!(Rules::from_toml(&moving), Err(Error::RuleMoveInvalid)), "move should be invalid");
+	assert!(matches!(Rules::from_toml(&action), Err(Error::RulesInvalid)), "rules should be invalid");
+}
@@ -0,0 +1,82 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Assimilation, Concept, Length, Letter, Pages, Radical, Rules, Script};
+
+#[test]
+fn derivation() {
+	let script = Script::IpaPeter;
+	let mut nasal = false;
+
+	for page in Pages::new(Length::L2) {
+		for word in page.into_iter().flatten().flatten().flatten() {
+			let derivation = word.derivation(script).expect("failed to derive word");
+
+			assert!(derivation.base().len() == word.structure().len(), "base should have a segment per letter");
+			assert!(
+				derivation.vowel_features().len() == derivation.segments().len(),
+				"every segment should have vowel features"
+			);
+
+			for (letter, features) in word.structure().iter().zip(derivation.vowel_features()) {
+				if let (Letter::Nasal(..), Some(features)) = (letter, features) {
+					assert!(features.is_nasal(), "vowel in nasal position should be nasal");
+					nasal = true;
+				}
+			}
+			assert!(derivation.steps().len() == Rules::default().rules().len(), "every rule should be a step");
+			assert!(
+				derivation.segments().concat() == word.to_string(script),
+				"segments should make up the word"
+			);
+
+			for step in derivation.steps() {
+				for change in step.changes() {
+					assert!(step.segments()[change.position()] == change.after(), "change is wrong");
+				}
+			}
+		}
+	}
+
+	// `ʔ` is removed at the beginning, `j` turns `i` dark
+	let word = Concept::from_radicals(&[
+		Radical::from_id(0).expect("failed to create radical"),
+		Radical::from_id(1).expect("failed to create radical"),
+	])
+	.expect("failed to create concept")
+	.into_iter()
+	.flatten()
+	.nth(1)
+	.expect("failed to create word");
+	let derivation = word.derivation(script).expect("failed to derive word");
+	let changes: Vec<_> = derivation
+		.steps()
+		.iter()
+		.flat_map(|step| {
+			return step
+				.changes()
+				.iter()
+				.map(move |change| return (step.assimilation(), change.position(), change.before(), change.after()));
+		})
+		.collect();
+
+	assert!(derivation.base() == ["ʔ", "i", "j"], "base is wrong");
+	assert!(
+		changes == [(Assimilation::Dark, 1, "i", "u"), (Assimilation::Removal, 0, "ʔ", "")],
+		"changes are wrong"
+	);
+	assert!(word.to_string(script) == "uj", "word is wrong");
+
+	assert!(nasal, "no nasal vowel found");
+	assert!(word.derivation(Script::UywiChiffre).is_none(), "UYWI Chiffre has no assimilations");
+}
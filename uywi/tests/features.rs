@@ -0,0 +1,53 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Articulation, Backness, Coloring, Concept, FormVowel, Height, Manner, Place, Radical, Script, Voicing};
+
+#[test]
+fn features() {
+	let k = Radical::from_id(6).expect("failed to create radical").features();
+
+	assert!(k.place() == Place::Velar, "place is wrong");
+	assert!(k.manner() == Manner::Plosive, "manner is wrong");
+	assert!(k.voicing() == Some(Voicing::Voiceless), "voicing is wrong");
+	assert!(k.articulation() == Articulation::Dark, "articulation is wrong");
+	assert!(k.coloring() == Some(Coloring::Shading), "coloring is wrong");
+
+	// the quality mark shown in concepts has to follow the features
+	for radical in Radical::all() {
+		let features = radical.features();
+		let string = Concept::from_radicals(&[radical])
+			.expect("failed to create concept")
+			.to_string(Script::IpaPeter);
+
+		assert!(features.is_dark() == string.ends_with('ˤ'), "dark radical is wrong");
+		assert!(features.is_light() == string.ends_with('ʲ'), "light radical is wrong");
+	}
+
+	assert!(
+		Radical::all().filter(|radical| return radical.features().is_voiceless()).count() == 18,
+		"number of voiceless radicals is wrong"
+	);
+	assert!(
+		Radical::all()
+			.filter(|radical| return radical.features().manner() == Manner::Nasal)
+			.count() == 3,
+		"number of nasal radicals is wrong"
+	);
+
+	let u = FormVowel::U.features();
+
+	assert!(u.height() == Height::Close, "height is wrong");
+	assert!(u.backness() == Backness::Back, "backness is wrong");
+	assert!(u.is_rounded() && !u.is_nasal(), "vowel is wrong");
+}
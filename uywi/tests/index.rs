@@ -0,0 +1,109 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Concept, Error, Length, Pages, Script, Word};
+
+#[test]
+fn concept_index() {
+	// indices have to stay the same, they are how concepts are referenced
+	let pinned: [(Length, &[(usize, &str)]); 5] = [
+		(Length::L1, &[(0, "?"), (1, "Y"), (22, "Z"), (43, "l")]),
+		(Length::L2, &[(0, "?h"), (42, "lh"), (43, "?2"), (1000, "4T"), (1891, "lw")]),
+		(Length::L3, &[(0, "?Yh"), (43, "Y2h"), (1000, "Dnh"), (39732, "?Yd"), (79463, "l?w")]),
+		(
+			Length::L4,
+			&[(0, "?Ywh"), (43, "?H2h"), (123_456, "0kf2"), (1_629_012, "?Ywd"), (3_258_023, "l?Yw")],
+		),
+		(
+			Length::L5,
+			&[
+				(0, "?Yw2h"),
+				(1000, "?Y02h"),
+				(9_876_543, "7PjsK"),
+				(65_160_480, "?Ywhd"),
+				(130_320_959, "lL?Yw"),
+			],
+		),
+	];
+
+	for (length, pinned) in &pinned {
+		for (index, string) in *pinned {
+			let concept = Concept::from_index(*index, *length).expect("failed to create concept");
+
+			assert!(concept.to_string(Script::UywiChiffre) == *string, "concept index is wrong");
+			assert!(concept.index() == *index, "concept index is wrong");
+		}
+
+		assert!(
+			matches!(Concept::from_index(length.num_of_concepts(), *length), Err(Error::ConceptIndexInvalid)),
+			"concept index should be invalid"
+		);
+	}
+
+	// L5 is too big for `touch_all`
+	for index in (0..Length::L5.num_of_concepts()).step_by(9973) {
+		let concept = Concept::from_index(index, Length::L5).expect("failed to create concept");
+
+		assert!(concept.index() == index, "concept index is wrong");
+		assert!(
+			concept
+				== Script::UywiChiffre
+					.from_concept(&concept.to_string(Script::UywiChiffre))
+					.expect("failed to create concept"),
+			"concept index is wrong"
+		);
+	}
+}
+
+#[test]
+fn word_index() {
+	for length in &[Length::L2, Length::L3, Length::L4] {
+		let mut index = 0;
+
+		for concept in Pages::new(*length).flatten().flatten().take(100) {
+			for word in concept.into_iter().flatten() {
+				assert!(index == word.index(), "word index is wrong");
+				assert!(
+					word == Word::from_index(index, *length).expect("failed to create word"),
+					"word index is wrong"
+				);
+				assert!(
+					word == Word::from_index_str(&word.index_as_string(), *length).expect("failed to create word"),
+					"word index string is wrong"
+				);
+
+				index += 1;
+			}
+		}
+
+		let last = Word::from_index(length.num_of_words() - 1, *length).expect("failed to create word");
+
+		assert!(last.concept().index() == length.num_of_concepts() - 1, "last word is wrong");
+		assert!(Word::from_index(length.num_of_words(), *length).is_err(), "word index should be invalid");
+	}
+
+	let word = Word::from_index_str("1234.3.2", Length::L3).expect("failed to create word");
+
+	assert!(word.concept().index_as_string() == "1234", "concept of word is wrong");
+	assert!(word.stem_index() == 2 && word.form_index() == 1, "word is wrong");
+	assert!(word.index_as_string() == "1234.3.2", "word index string is wrong");
+
+	for invalid in &["1234.3", "1234.3.2.1", "1234.x.2", "1234.0.2"] {
+		assert!(Word::from_index_str(invalid, Length::L3).is_err(), "word index string should be invalid");
+	}
+
+	assert!(
+		matches!(Word::from_index_str("1234.6.2", Length::L3), Err(Error::StemIndexInvalid)),
+		"stem should be invalid"
+	);
+}
@@ -0,0 +1,84 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use rayon::prelude::*;
+use uywi::{Concept, ConceptOrWord, Length, Script, Word};
+
+/// Check that every `step`th concept of `length` and all its words are read back from [`Script::IpaPeter`].
+fn ipa_peter_length(length: Length, step: usize) {
+	let script = Script::IpaPeter;
+
+	(0..length.num_of_concepts())
+		.into_par_iter()
+		.filter(|index| return index % step == 0)
+		.for_each(|index| {
+			let concept = Concept::from_index(index, length).expect("failed to create concept");
+
+			assert!(
+				concept == script.from_concept(&concept.to_string(script)).expect("failed to create concept"),
+				"concept string is wrong"
+			);
+
+			for word in concept.into_iter().flatten() {
+				// different words can have the same string, so the word only has to be one of them
+				assert!(
+					script
+						.parse_all(&word.to_string(script))
+						.expect("failed to create word")
+						.contains(&ConceptOrWord::Word(word)),
+					"word string is wrong"
+				);
+			}
+		});
+}
+
+#[test]
+fn ipa_peter() {
+	// reading a word takes around 10µs, so we check every word of `L1` to `L3` and the words of some concepts of `L4` and `L5`
+	ipa_peter_length(Length::L1, 1);
+	ipa_peter_length(Length::L2, 1);
+	ipa_peter_length(Length::L3, 1);
+	ipa_peter_length(Length::L4, 101);
+	ipa_peter_length(Length::L5, 10007);
+}
+
+#[test]
+fn ipa_peter_sampled() {
+	let script = Script::IpaPeter;
+
+	// words spread over the whole index of every length, the step moves on by one stem or form every time so all of them are reached
+	for length in &Length::all() {
+		let words_per_concept = length.stems_per_concept() * length.words_per_stem();
+		let step = length.num_of_words() / 2000 / words_per_concept * words_per_concept + 1;
+
+		(0..length.num_of_words()).step_by(step).par_bridge().for_each(|index| {
+			let word = Word::from_index(index, *length).expect("failed to create word");
+
+			assert!(
+				script
+					.parse_all(&word.to_string(script))
+					.expect("failed to create word")
+					.contains(&ConceptOrWord::Word(word)),
+				"word string is wrong"
+			);
+		});
+	}
+}
+
+/// Every word of `L4` and `L5`, takes hours on a single core.
+#[test]
+#[ignore]
+fn ipa_peter_all() {
+	ipa_peter_length(Length::L4, 1);
+	ipa_peter_length(Length::L5, 1);
+}
@@ -0,0 +1,116 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use rayon::prelude::*;
+use uywi::{Concept, ConceptOrWord, Error, Length, Pages, Radical, Script, NUM_OF_RADICALS};
+
+#[test]
+fn latin() {
+	let script = Script::Latin;
+
+	// every radical and vowel, in case the built-in ones change
+	let radicals: Vec<_> = Pages::new(Length::L1)
+		.flat_map(|page| return page.into_iter().flatten())
+		.map(|concept| return concept.to_string(script))
+		.collect();
+	let mut symbols = radicals.clone();
+	symbols.extend(["a", "e", "i", "u"].iter().map(|vowel| return (*vowel).to_owned()));
+
+	assert!(radicals.len() == NUM_OF_RADICALS, "number of radicals is wrong");
+	assert!(
+		symbols
+			.iter()
+			.all(|symbol| return symbol.chars().all(|char| return char.is_ascii_lowercase() && char != 'o')),
+		"symbol isn't a lowercase letter"
+	);
+	assert!(uniquely_decodable(&symbols), "symbols can be read in more then one way");
+
+	[Length::L1, Length::L2, Length::L3].into_par_iter().for_each(|length| {
+		Pages::new(*length).par_bridge().for_each(|page| {
+			page.into_iter().par_bridge().for_each(|row| {
+				for (position, concept) in row.enumerate() {
+					let parsed = script.from_str(&concept.to_string(script)).expect("failed to create concept");
+
+					assert!(parsed.concept_or_word() == ConceptOrWord::Concept(concept), "concept string is wrong");
+					assert!(!parsed.is_ambiguous(), "concept string is ambiguous");
+
+					// building words from strings is expensive, so we check all of `L1` and `L2` and one concept per row of `L3`
+					if *length == Length::L3 && position > 0 {
+						continue;
+					}
+
+					for word in concept.into_iter().flatten() {
+						let parsed = script.from_str(&word.to_string(script)).expect("failed to create word");
+
+						assert!(parsed.concept_or_word() == ConceptOrWord::Word(word), "word string is wrong");
+						assert!(!parsed.is_ambiguous(), "word string is ambiguous");
+					}
+				}
+			});
+		});
+	});
+
+	assert!(
+		script.from_concept("shh").expect("failed to create concept")
+			== Concept::from_radicals(&[
+				Radical::from_id(20).expect("failed to create radical"),
+				Radical::from_id(3).expect("failed to create radical")
+			])
+			.expect("failed to create concept"),
+		"`shh` should be `s` and `hh`"
+	);
+	assert!(
+		matches!(script.from_concept("h"), Err(Error::ConceptRadicalInvalid)),
+		"radical should be invalid"
+	);
+	assert!(matches!(script.from_str("ınhı"), Err(Error::WordInvalid)), "word should be invalid");
+}
+
+/// Check if no string can be split into `symbols` in more then one way, using the Sardinas–Patterson algorithm.
+fn uniquely_decodable(symbols: &[String]) -> bool {
+	// suffixes left over when a string of `from` is the start of a string of `to`
+	let dangling = |from: &[String], to: &[String]| {
+		let mut suffixes: Vec<String> = from
+			.iter()
+			.flat_map(|start| {
+				return to
+					.iter()
+					.filter(move |string| return string.len() > start.len() && string.starts_with(start.as_str()))
+					.map(move |string| return string[start.len()..].to_owned());
+			})
+			.collect();
+		suffixes.sort();
+		suffixes.dedup();
+
+		return suffixes;
+	};
+
+	let mut seen: Vec<Vec<String>> = Vec::new();
+	let mut suffixes = dangling(symbols, symbols);
+
+	while !suffixes.is_empty() && !seen.contains(&suffixes) {
+		if suffixes.iter().any(|suffix| return symbols.contains(suffix)) {
+			return false;
+		}
+
+		let mut next = dangling(&suffixes, symbols);
+		next.extend(dangling(symbols, &suffixes));
+		next.sort();
+		next.dedup();
+
+		seen.push(suffixes);
+		suffixes = next;
+	}
+
+	return true;
+}
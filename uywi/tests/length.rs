@@ -0,0 +1,38 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use rayon::prelude::*;
+use uywi::{Length, Pages, Script};
+
+#[test]
+fn length_5() {
+	// `L5` is too big to touch everything, so we check the first row of some pages
+	Pages::new(Length::L5).step_by(997).par_bridge().for_each(|page| {
+		let row = page.into_iter().next().expect("failed to create row");
+		let mut index = page.index() * Length::L5.concepts_per_page();
+
+		for concept in row {
+			assert!(index == concept.index(), "concept index is wrong");
+			assert!(concept.page().index() == page.index(), "concept page is wrong");
+
+			for script in &[Script::UywiChiffre, Script::IpaPeter] {
+				assert!(
+					concept == script.from_concept(&concept.to_string(*script)).expect("failed to create concept"),
+					"concept string is wrong"
+				);
+			}
+
+			index += 1;
+		}
+	});
+}
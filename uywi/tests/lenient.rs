@@ -0,0 +1,83 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Error, Length, Pages, Script};
+
+#[test]
+fn lenient() {
+	// `ç` decomposed into `c` and a combining cedilla
+	let concept = Script::IpaPeter.from_concept("\u{e7}ʲʁ").expect("failed to create concept");
+
+	assert!(
+		Script::IpaPeter.from_concept("c\u{327}ʲʁ").expect("failed to create concept") == concept,
+		"string should be normalized"
+	);
+
+	let script = Script::UywiChiffre;
+	let word = Pages::new(Length::L2)
+		.next()
+		.expect("failed to create page")
+		.into_iter()
+		.flatten()
+		.flatten()
+		.flatten()
+		.find(|word| return word.to_string(script).contains('ı'))
+		.expect("failed to find word");
+	let string = word.to_string(script);
+	let dotted = string.replace('ı', "i");
+
+	assert!(script.from_str(&dotted).is_err(), "dotted `i` should be invalid");
+
+	let lenient = script.from_str_lenient(&dotted).expect("failed to create word");
+
+	assert!(lenient.parsed().to_string(script) == string, "word string is wrong");
+	assert!(
+		lenient.substitutions().len() == string.matches('ı').count(),
+		"number of substitutions is wrong"
+	);
+	assert!(
+		lenient
+			.substitutions()
+			.iter()
+			.all(|substitution| return substitution.from() == "i" && substitution.to() == "ı" && &dotted[substitution.position()..][..1] == "i"),
+		"substitution is wrong"
+	);
+
+	// `O` and `0` are both radicals, so neither is read as the other
+	assert!(script.from_str_lenient("OO").is_err(), "concept should be invalid");
+	assert!(
+		script
+			.from_str_lenient("O0")
+			.expect("failed to create concept")
+			.substitutions()
+			.is_empty(),
+		"valid string shouldn't be substituted"
+	);
+	assert!(
+		script
+			.from_str_lenient(&string)
+			.expect("failed to create word")
+			.substitutions()
+			.is_empty(),
+		"valid string shouldn't be substituted"
+	);
+	assert!(
+		matches!(script.from_str_lenient("ééé"), Err(Error::WordInvalid)),
+		"string should be invalid"
+	);
+
+	let lenient = Script::Latin.from_str_lenient("Shak").expect("failed to create word");
+
+	assert!(lenient.parsed().to_string(Script::Latin) == "shak", "word string is wrong");
+	assert!(lenient.substitutions().len() == 1, "number of substitutions is wrong");
+}
@@ -0,0 +1,52 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used
+)]
+
+use uywi::{Concept, Config, Error, Form, FormVowel, Length, StemTemplate, Word};
+
+// a `Config` can only be installed once, so every limit is tested with the same one
+#[test]
+fn limits() {
+	let mut config = Config::new();
+	let template: StemTemplate = "c0 v0 c1".parse().expect("failed to parse template");
+
+	assert!(
+		matches!(
+			config.set_templates(Length::L2, vec![template.clone(); 256]),
+			Err(Error::TemplatesTooMany)
+		),
+		"templates should be too many"
+	);
+
+	config.set_templates(Length::L2, vec![template; 255]).expect("failed to set templates");
+	let form = Form::new(FormVowel::A, FormVowel::E);
+
+	assert!(
+		matches!(config.set_forms(Length::L2, vec![form; 256]), Err(Error::FormsTooMany)),
+		"forms should be too many"
+	);
+
+	config.set_forms(Length::L2, vec![form; 255]).expect("failed to set forms");
+	config.install().expect("failed to install config");
+
+	let concept = Concept::from_index(0, Length::L2).expect("failed to create concept");
+	let words: Vec<Word> = concept.into_iter().flatten().collect();
+	let last = *words.last().expect("no words");
+
+	assert!(words.len() == 255 * Length::L2.words_per_stem(), "number of words is wrong");
+	assert!(last.stem_index() == 254, "stem index is wrong");
+	assert!(
+		last.index_as_string() == format!("1.255.{}", Length::L2.words_per_stem()),
+		"index string is wrong"
+	);
+	assert!(
+		Word::from_index(last.index(), Length::L2).expect("failed to create word") == last,
+		"word index is wrong"
+	);
+}
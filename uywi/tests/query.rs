@@ -0,0 +1,128 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Error, Length, Letter, Pages, Query, Script};
+
+#[test]
+fn query() {
+	let script = Script::UywiChiffre;
+	let words: Vec<_> = Pages::new(Length::L2)
+		.flat_map(|page| return page.into_iter().flatten().flatten().flatten())
+		.collect();
+	let matches = |query: &Query| return query.words(Length::L2).collect::<Vec<_>>();
+
+	// every letter of UYWI Chiffre is one character, so the query can be checked on characters
+	let query = Query::new(script, "k?h*ı").expect("failed to create query");
+	let expected: Vec<_> = words
+		.iter()
+		.copied()
+		.filter(|word| {
+			let chars: Vec<_> = word.to_string(script).chars().collect();
+
+			return (0..chars.len()).any(|start| {
+				return chars[start] == 'k'
+					&& chars.get(start + 2) == Some(&'h')
+					&& chars.get(start + 3..).map_or(false, |chars| return chars.contains(&'ı'));
+			});
+		})
+		.collect();
+
+	assert!(!expected.is_empty(), "query should match words");
+	assert!(matches(&query) == expected, "words are wrong");
+
+	let query = Query::new(script, "ı$").expect("failed to create query");
+	let expected: Vec<_> = words
+		.iter()
+		.copied()
+		.filter(|word| return word.to_string(script).ends_with('ı'))
+		.collect();
+	assert!(matches(&query) == expected, "words are wrong");
+
+	// `?` has to be escaped to be read as a radical
+	let query = Query::new(script, "^\\?").expect("failed to create query");
+	let expected: Vec<_> = words
+		.iter()
+		.copied()
+		.filter(|word| return word.to_string(script).starts_with('?'))
+		.collect();
+	assert!(!expected.is_empty(), "query should match words");
+	assert!(matches(&query) == expected, "words are wrong");
+
+	// classes only know the features of the letter itself
+	let query = Query::new(script, "^[dark consonant]").expect("failed to create query");
+	let expected: Vec<_> = words
+		.iter()
+		.copied()
+		.filter(|word| {
+			return match word.structure()[0] {
+				Letter::Consonant(index) => word.concept().radicals()[usize::from(index)].features().is_dark(),
+				_ => false,
+			};
+		})
+		.collect();
+	assert!(!expected.is_empty(), "query should match words");
+	assert!(matches(&query) == expected, "words are wrong");
+
+	// `ʔ` is removed at the beginning, wherever it is written
+	let query = Query::new(script, "[beginning]").expect("failed to create query");
+	let expected: Vec<_> = words.iter().copied().filter(|word| return word.to_string(script).contains('?')).collect();
+	assert!(!expected.is_empty(), "query should match words");
+	assert!(matches(&query) == expected, "words are wrong");
+
+	let query = Query::new(script, "[alternative]").expect("failed to create query");
+	assert!(
+		matches(&query)
+			.iter()
+			.all(|word| return word.concept().radicals().iter().any(|radical| return [10, 11].contains(&radical.id()))),
+		"words are wrong"
+	);
+	assert!(!matches(&query).is_empty(), "query should match words");
+
+	let query = Query::new(script, "^k").expect("failed to create query");
+	assert!(
+		query.concepts(Length::L2).eq(Pages::new(Length::L2)
+			.flat_map(|page| return page.into_iter().flatten())
+			.filter(|concept| return concept.to_string(script).starts_with('k'))),
+		"concepts are wrong"
+	);
+
+	// literals only match whole letters
+	let query = Query::new(Script::Latin, "^s").expect("failed to create query");
+	let sh: Vec<_> = words
+		.iter()
+		.filter(|word| return word.to_string(Script::Latin).starts_with("sh"))
+		.collect();
+	assert!(!sh.is_empty(), "words should start with `sh`");
+	assert!(sh.iter().all(|word| return !query.matches_word(**word)), "`s` shouldn't match `sh`");
+
+	assert!(
+		matches!(Query::new(script, "k^o"), Err(Error::QueryInvalid(1))),
+		"anchor should be invalid"
+	);
+	assert!(
+		matches!(Query::new(script, "k[vowel"), Err(Error::QueryInvalid(1))),
+		"class should be invalid"
+	);
+	assert!(
+		matches!(Query::new(script, "[#]"), Err(Error::QueryInvalid(0))),
+		"class should be invalid"
+	);
+	assert!(
+		matches!(Query::new(script, "k\\"), Err(Error::QueryInvalid(1))),
+		"escape should be invalid"
+	);
+	assert!(
+		matches!(Query::new(script, "[long]"), Err(Error::FeatureInvalid)),
+		"feature should be invalid"
+	);
+}
@@ -0,0 +1,109 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use std::fmt::Write;
+use uywi::{
+	arrayvec::ArrayString, Concept, ConceptOrWord, Config, Error, Extension, Length, Letter, Radical, Script, ScriptRegistry, Vowel, Word,
+	CONCEPT_BUFFER, WORD_BUFFER,
+};
+
+/// Script writing radicals as their index with two digits.
+#[derive(Debug)]
+struct Digits;
+
+impl Extension for Digits {
+	fn name(&self) -> &str {
+		return "Digits";
+	}
+
+	fn from_concept(&self, string: &str) -> Result<Concept, Error> {
+		let mut radicals = Vec::new();
+
+		for radical in string.as_bytes().chunks(2) {
+			let index: u8 = std::str::from_utf8(radical)
+				.ok()
+				.and_then(|radical| return radical.parse().ok())
+				.filter(|index| return usize::from(*index) < Config::current().num_of_radicals())
+				.ok_or(Error::ConceptRadicalInvalid)?;
+
+			radicals.push(Radical::from_index(index));
+		}
+
+		return Concept::from_radicals(&radicals);
+	}
+
+	fn parse_all(&self, string: &str) -> Result<Vec<ConceptOrWord>, Error> {
+		return Ok(vec![ConceptOrWord::Concept(self.from_concept(string)?)]);
+	}
+
+	fn concept(&self, concept: Concept) -> ArrayString<[u8; CONCEPT_BUFFER]> {
+		let mut string = ArrayString::new();
+
+		for radical in concept.radicals() {
+			write!(string, "{:02}", radical.index()).expect("failed to write radical");
+		}
+
+		return string;
+	}
+
+	fn word(&self, word: Word) -> ArrayString<[u8; WORD_BUFFER]> {
+		let mut string = ArrayString::new();
+
+		for letter in word.structure() {
+			match letter {
+				Letter::Consonant(index) | Letter::DuplicateConsonant(index) => {
+					write!(string, "{:02}", word.concept().radicals()[usize::from(index)].index()).expect("failed to write radical")
+				},
+				Letter::Vowel(Vowel::First) | Letter::DuplicateVowel(Vowel::First) | Letter::Nasal(Vowel::First) => string.push('-'),
+				Letter::Vowel(Vowel::Last) | Letter::DuplicateVowel(Vowel::Last) | Letter::Nasal(Vowel::Last) => string.push('+'),
+			}
+		}
+
+		return string;
+	}
+}
+
+#[test]
+fn registry() {
+	let mut registry = ScriptRegistry::new();
+
+	assert!(registry.get("Uywi Chiffre") == Some(Script::UywiChiffre), "built-in script is missing");
+	assert!(registry.get("Digits").is_none(), "script shouldn't be registered yet");
+
+	let script = registry.register(&Digits).expect("failed to register script");
+
+	assert!(registry.get("Digits") == Some(script), "script wasn't registered");
+	assert!(registry.register(&Digits).is_err(), "script was registered twice");
+	assert!(registry.iter().count() == 5, "wrong number of scripts");
+	assert!(script.to_string() == "Digits", "script name is wrong");
+
+	let concept = Concept::from_index(1234, Length::L3).expect("failed to create concept");
+	let string = concept.to_string(script);
+
+	assert!(string == "301803", "concept string is wrong");
+	assert!(
+		script.from_str(&string).expect("failed to create concept").concept_or_word() == ConceptOrWord::Concept(concept),
+		"concept string is wrong"
+	);
+
+	let word = concept.into_iter().flatten().next().expect("failed to create word");
+
+	assert!(word.to_string(script) == "30-18+03", "word string is wrong");
+	// only the required methods are implemented, so every optional capability is missing
+	assert!(
+		script.phonology().is_none() && script.orthography().is_none() && script.lettering().is_none(),
+		"script shouldn't have capabilities"
+	);
+	assert!(word.derivation(script).is_none(), "script has no assimilations");
+	assert!(word.syllables(script).is_none(), "script has no syllables");
+}
@@ -0,0 +1,108 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used
+)]
+
+use std::collections::BTreeSet;
+use unicode_segmentation::UnicodeSegmentation;
+use uywi::{Action, Concept, ConceptOrWord, Config, Context, Length, Pages, Pattern, Radical, Rule, Rules, Script, Side, Word};
+
+// a `Config` can only be installed once, so rules other scripts might not be read with get their own test
+#[test]
+fn rules() {
+	let pattern = |string: &str| return string.parse::<Pattern>().expect("failed to parse pattern");
+	let mut rules: Vec<Rule> = Rules::default()
+		.rules()
+		.iter()
+		.filter(|rule| return rule.name() != "rounding")
+		.cloned()
+		.collect();
+	// `h` is removed between vowels, not only at the end
+	rules.push(
+		Rule::new(
+			"medial",
+			pattern("consonant end"),
+			vec![
+				Context::new(Side::Before, pattern("vowel"), Some(pattern("duplicate"))),
+				Context::new(Side::After, pattern("vowel"), Some(pattern("duplicate"))),
+			],
+			Action::Remove,
+		)
+		.expect("failed to create rule"),
+	);
+	// duplicates are never written
+	rules.push(Rule::new("duplicate", pattern("duplicate"), Vec::new(), Action::Remove).expect("failed to create rule"));
+
+	let mut config = Config::new();
+	config.set_ipa_rules(Rules::new(rules));
+	config.install().expect("failed to install config");
+
+	let radical = |id| return Radical::from_id(id).expect("failed to create radical");
+	let mut concepts: Vec<Concept> = vec![
+		Concept::from_radicals(&[radical(3), radical(40)]).expect("failed to create concept"),
+		Concept::from_radicals(&[radical(6), radical(3), radical(36)]).expect("failed to create concept"),
+		Concept::from_radicals(&[radical(20), radical(3), radical(34), radical(40)]).expect("failed to create concept"),
+	];
+
+	for length in &Length::all() {
+		concepts.extend((0..3).map(|index| return Concept::from_index(index, *length).expect("failed to create concept")));
+	}
+
+	let mut medial = false;
+
+	for concept in &concepts {
+		let words: Vec<Word> = concept.into_iter().flatten().collect();
+
+		for word in &words {
+			for script in &[Script::IpaPeter, Script::XSampa] {
+				let string = word.to_string(*script);
+
+				assert!(
+					script
+						.parse_all(&string)
+						.expect("failed to create word")
+						.contains(&ConceptOrWord::Word(*word)),
+					"{} word {} wasn't found",
+					script,
+					string
+				);
+			}
+
+			medial |= concept.radicals().contains(&radical(3)) && !word.to_string(Script::IpaPeter).contains('h');
+		}
+	}
+
+	assert!(medial, "`h` should be removed between vowels");
+
+	// completions follow the rules too
+	let strings: Vec<_> = Pages::new(Length::L2)
+		.flat_map(|page| return page.into_iter().flatten())
+		.flat_map(|concept| return concept.into_iter().flatten().map(|word| return word.to_string(Script::IpaPeter)))
+		.collect();
+
+	for prefix in &["a", "ʕi", "ka"] {
+		let completions = Script::IpaPeter.complete(prefix, Length::L2, usize::MAX);
+		let next: BTreeSet<_> = strings
+			.iter()
+			.filter(|string| return string.starts_with(prefix))
+			.filter_map(|string| return string[prefix.len()..].graphemes(true).next().map(str::to_owned))
+			.collect();
+
+		assert!(!next.is_empty(), "{} should be completed", prefix);
+		assert!(completions.next().iter().cloned().eq(next), "next graphemes are wrong");
+		assert!(
+			completions
+				.completions()
+				.iter()
+				.filter(|completion| return matches!(completion.concept_or_word(), ConceptOrWord::Word(..)))
+				.count() == strings.iter().filter(|string| return string.starts_with(prefix)).count(),
+			"number of completions is wrong"
+		);
+	}
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "serde")]
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{Concept, ConceptOrWord, Length, Page, Script, Word};
+
+#[test]
+fn round_trip() {
+	let concept = Concept::from_index(1234, Length::L3).expect("failed to create concept");
+	let word = Word::from_concept(concept, 2, 3).expect("failed to create word");
+	let page = concept.page();
+
+	assert!(serde_json::to_string(&Length::L3).expect("failed to serialize") == "3", "length is wrong");
+	assert!(
+		serde_json::to_string(&concept).expect("failed to serialize") == "[3,1234]",
+		"concept is wrong"
+	);
+	assert!(
+		serde_json::to_string(&word).expect("failed to serialize") == "[3,1234,2,3]",
+		"word is wrong"
+	);
+	assert!(serde_json::to_string(&page).expect("failed to serialize") == "[3,0]", "page is wrong");
+	assert!(
+		serde_json::to_string(&Script::IpaPeter).expect("failed to serialize") == r#""IPA (Peter's script)""#,
+		"script is wrong"
+	);
+
+	assert!(
+		serde_json::from_str::<Length>("3").expect("failed to deserialize") == Length::L3,
+		"length is wrong"
+	);
+	assert!(
+		serde_json::from_str::<Concept>("[3,1234]").expect("failed to deserialize") == concept,
+		"concept is wrong"
+	);
+	assert!(
+		serde_json::from_str::<Word>("[3,1234,2,3]").expect("failed to deserialize") == word,
+		"word is wrong"
+	);
+	assert!(
+		serde_json::from_str::<Page>("[3,0]").expect("failed to deserialize").index() == page.index(),
+		"page is wrong"
+	);
+	assert!(
+		serde_json::from_str::<Script>(r#""IPA (Peter's script)""#).expect("failed to deserialize") == Script::IpaPeter,
+		"script is wrong"
+	);
+
+	for concept_or_word in &[ConceptOrWord::Concept(concept), ConceptOrWord::Word(word)] {
+		let string = serde_json::to_string(concept_or_word).expect("failed to serialize");
+
+		assert!(
+			serde_json::from_str::<ConceptOrWord>(&string).expect("failed to deserialize") == *concept_or_word,
+			"concept or word is wrong"
+		);
+	}
+}
+
+#[test]
+fn invalid() {
+	assert!(serde_json::from_str::<Length>("6").is_err(), "length should be invalid");
+	assert!(serde_json::from_str::<Concept>("[2,1892]").is_err(), "concept should be invalid");
+	assert!(serde_json::from_str::<Word>("[2,0,3,0]").is_err(), "stem should be invalid");
+	assert!(serde_json::from_str::<Word>("[2,0,0,2]").is_err(), "form should be invalid");
+	assert!(serde_json::from_str::<Page>("[3,44]").is_err(), "page should be invalid");
+	assert!(serde_json::from_str::<Script>(r#""Digits""#).is_err(), "script should be invalid");
+
+	let error = serde_json::from_str::<Concept>("[2,1892]").expect_err("concept should be invalid");
+
+	assert!(error.to_string().starts_with("Index of concept is invalid."), "error is wrong");
+}
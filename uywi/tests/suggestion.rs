@@ -0,0 +1,86 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{ConceptOrWord, Length, Mistake, Pages, Script};
+
+#[test]
+fn suggest() {
+	let script = Script::UywiChiffre;
+	// `Bocoh` with one letter replaced, a duplicate radical or an unknown letter
+	let replaced = script.suggest("Bocxh", 10);
+	let duplicate = script.suggest("BocoB", 10);
+	let unknown = script.suggest("Boc\u{e9}h", 10);
+
+	assert!(replaced.mistake() == Some(Mistake::TemplateMissing), "mistake is wrong");
+	assert!(duplicate.mistake() == Some(Mistake::RadicalDuplicate(4)), "mistake is wrong");
+	assert!(unknown.mistake() == Some(Mistake::GraphemeUnknown(3)), "mistake is wrong");
+	assert!(script.suggest("", 10).mistake() == Some(Mistake::Empty), "mistake is wrong");
+	assert!(script.suggest("Bocoh", 10).mistake().is_none(), "word should be valid");
+
+	for suggestions in &[replaced, duplicate, unknown] {
+		assert!(suggestions.suggestions().len() == 10, "number of suggestions is wrong");
+		assert!(
+			suggestions
+				.suggestions()
+				.windows(2)
+				.all(|pair| return pair[0].distance() <= pair[1].distance()),
+			"suggestions should be ranked by distance"
+		);
+		assert!(suggestions.suggestions()[0].distance() == 1, "nearest suggestion should be one edit away");
+	}
+
+	assert!(
+		script
+			.suggest("Bocxh", usize::MAX)
+			.suggestions()
+			.iter()
+			.any(|suggestion| return suggestion.concept_or_word().to_string(script) == "Bocoh"),
+		"`Bocoh` should be suggested"
+	);
+
+	// `Bocoh` with two letters added, nothing is one edit away
+	let twice = script.suggest("oBocoho", usize::MAX);
+
+	assert!(twice.mistake() == Some(Mistake::TemplateMissing), "mistake is wrong");
+	assert!(twice.suggestions()[0].distance() == 2, "nearest suggestion should be two edits away");
+	assert!(
+		twice
+			.suggestions()
+			.iter()
+			.any(|suggestion| return suggestion.concept_or_word().to_string(script) == "Bocoh"),
+		"`Bocoh` should be suggested"
+	);
+}
+
+#[test]
+fn suggest_affricate() {
+	let script = Script::IpaPeter;
+	// a word with the affricate `t͡s` replaced by `d͡ʒ`, which can't be read, both are two graphemes but a single letter
+	let (word, string) = Pages::new(Length::L2)
+		.flat_map(|page| return page.into_iter().flatten().flatten().flatten())
+		.map(|word| return (word, word.to_string(script)))
+		.filter(|(_, string)| return string.contains("t͡s"))
+		.map(|(word, string)| return (word, string.replacen("t͡s", "d͡ʒ", 1)))
+		.find(|(_, string)| return script.parse_all(string).is_err())
+		.expect("failed to find word");
+	let suggestions = script.suggest(&string, usize::MAX);
+
+	assert!(suggestions.mistake().is_some(), "word should be invalid");
+	assert!(
+		suggestions
+			.suggestions()
+			.iter()
+			.any(|suggestion| return suggestion.concept_or_word() == ConceptOrWord::Word(word) && suggestion.distance() == 1),
+		"word should be suggested one edit away"
+	);
+}
@@ -0,0 +1,61 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{ConceptOrWord, Length, Pages, Script};
+
+#[test]
+fn syllables() {
+	let script = Script::IpaPeter;
+
+	for length in &[Length::L1, Length::L2, Length::L3] {
+		for page in Pages::new(*length).step_by(7) {
+			for word in page.into_iter().flatten().flatten().flatten() {
+				let string = word.to_string(script);
+				let syllables = word.syllables(script).expect("failed to find syllables");
+
+				assert!(
+					syllables.iter().map(|syllable| return &string[syllable.range()]).collect::<String>() == string,
+					"syllables should make up the word"
+				);
+				assert!(
+					syllables.is_empty() || syllables.iter().filter(|syllable| return syllable.is_stressed()).count() == 1,
+					"one syllable should be stressed"
+				);
+				assert!(
+					word.to_string_syllabified(script)
+						.expect("failed to find syllables")
+						.replace(&['.', 'ˈ'][..], "")
+						== string,
+					"syllabified word is wrong"
+				);
+			}
+		}
+	}
+
+	// the doubled consonant starts the second syllable and closes the first one
+	let word = match script.from_str("xaħːɑ̃").expect("failed to create word").concept_or_word() {
+		ConceptOrWord::Word(word) => word,
+		ConceptOrWord::Concept(_) => panic!("word should be a word"),
+	};
+	let syllables = word.syllables(script).expect("failed to find syllables");
+
+	assert!(syllables.len() == 2, "number of syllables is wrong");
+	assert!(syllables[0].range() == (0..2), "syllable is wrong");
+	assert!(syllables[0].is_heavy() && syllables[0].is_stressed(), "syllable is wrong");
+	assert!(!syllables[1].is_heavy() && !syllables[1].is_stressed(), "syllable is wrong");
+	assert!(
+		word.to_string_syllabified(script).expect("failed to find syllables") == "ˈxa.ħːɑ̃",
+		"syllabified word is wrong"
+	);
+	assert!(word.syllables(Script::UywiChiffre).is_none(), "UYWI Chiffre has no syllables");
+}
@@ -0,0 +1,71 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used
+)]
+
+use uywi::{Concept, ConceptOrWord, Config, Error, Length, Radical, Script, StemTemplate, Word};
+
+// a `Config` can only be installed once, so every script is tested with the same one
+#[test]
+fn templates() {
+	let mut config = Config::new();
+	let template = |string: &str| return string.parse::<StemTemplate>().expect("failed to parse template");
+
+	// consonants out of order
+	config
+		.set_templates(Length::L2, vec![template("c1 v0 c0"), template("c1 v0 xc1 c0 v1")])
+		.expect("failed to set templates");
+	config
+		.set_templates(Length::L3, vec![template("c2 v0 c0 v1 c1")])
+		.expect("failed to set templates");
+	// without `w`
+	config
+		.set_radicals((0..44).filter(|id| return *id != 2).collect())
+		.expect("failed to set radicals");
+	config.install().expect("failed to install config");
+
+	let radical = |id| return Radical::from_id(id).expect("failed to create radical");
+
+	for concept in &[
+		Concept::from_radicals(&[radical(0), radical(36)]).expect("failed to create concept"),
+		Concept::from_radicals(&[radical(20), radical(6), radical(40)]).expect("failed to create concept"),
+	] {
+		let words: Vec<Word> = concept.into_iter().flatten().collect();
+
+		for script in &[Script::UywiChiffre, Script::IpaPeter, Script::XSampa, Script::Latin] {
+			for word in &words {
+				let string = word.to_string(*script);
+
+				assert!(
+					script
+						.parse_all(&string)
+						.expect("failed to create word")
+						.contains(&ConceptOrWord::Word(*word)),
+					"{} word {} wasn't found",
+					script,
+					string
+				);
+			}
+		}
+	}
+
+	let word = Concept::from_radicals(&[radical(0), radical(36)])
+		.expect("failed to create concept")
+		.into_iter()
+		.flatten()
+		.next()
+		.expect("no words");
+
+	assert!(word.to_string(Script::Latin) == "maq", "word string is wrong");
+	// `w` isn't part of the radical inventory
+	assert!(
+		matches!(Script::Latin.parse_all("maw"), Err(Error::WordInvalid)),
+		"word should be invalid"
+	);
+}
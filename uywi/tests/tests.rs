@@ -18,7 +18,7 @@ use uywi::{Concept, Length, Pages, Script};
 fn touch_all() {
 	let script = Script::UywiChiffre;
 
-	[Length::L2, Length::L3, Length::L4].into_par_iter().for_each(|length| {
+	[Length::L1, Length::L2, Length::L3, Length::L4].into_par_iter().for_each(|length| {
 		Pages::new(*length).par_bridge().for_each(|page| {
 			page.into_iter().par_bridge().for_each(|row| {
 				let mut index = page.index() * length.concepts_per_page() + row.index() * length.concepts_per_row();
@@ -0,0 +1,63 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use uywi::{tokenize, transliterate, Concept, Length, Script, Token, TokenKind};
+
+#[test]
+fn text() {
+	let word = Concept::from_index(1234, Length::L3)
+		.expect("failed to create concept")
+		.into_iter()
+		.flatten()
+		.next()
+		.expect("failed to create word");
+	let text = "«Bocoh», hello Bocoh?  ?o";
+	let tokens = tokenize(text, Script::UywiChiffre);
+	let kinds: Vec<_> = tokens.iter().map(Token::kind).collect();
+
+	assert!(word.to_string(Script::UywiChiffre) == "Bocoh", "word string is wrong");
+	assert!(
+		tokens.iter().map(Token::as_str).collect::<String>() == text,
+		"tokens should cover the whole text"
+	);
+	assert!(
+		matches!(
+			kinds.as_slice(),
+			[
+				TokenKind::Punctuation,
+				TokenKind::Word(..),
+				TokenKind::Punctuation,
+				TokenKind::Whitespace,
+				TokenKind::Untranslatable,
+				TokenKind::Whitespace,
+				TokenKind::Word(..),
+				TokenKind::Punctuation,
+				TokenKind::Whitespace,
+				TokenKind::Word(..),
+			]
+		),
+		"token kinds are wrong"
+	);
+	assert!(tokens[2].as_str() == "»,", "punctuation is wrong");
+	// `?` is a radical in Chiffre, so it's only punctuation if the word can't be read with it
+	assert!(tokens[9].as_str() == "?o", "word is wrong");
+
+	let transliteration = transliterate(text, Script::UywiChiffre, Script::IpaPeter);
+
+	assert!(transliteration.text() == "«θeɕe», hello θeɕe?  a", "transliteration is wrong");
+	assert!(transliteration.untranslatable() == [11..16], "untranslatable ranges are wrong");
+	assert!(
+		&text[transliteration.untranslatable()[0].clone()] == "hello",
+		"untranslatable range is wrong"
+	);
+}
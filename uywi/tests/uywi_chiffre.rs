@@ -0,0 +1,38 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use rayon::prelude::*;
+use uywi::{ConceptOrWord, Length, Pages, Script};
+
+#[test]
+fn uywi_chiffre() {
+	let script = Script::UywiChiffre;
+
+	[Length::L1, Length::L2, Length::L3].into_par_iter().for_each(|length| {
+		Pages::new(*length).par_bridge().for_each(|page| {
+			page.into_iter().par_bridge().for_each(|row| {
+				for concept in row {
+					for word in concept.into_iter().flatten() {
+						assert!(
+							script
+								.parse_all(&word.to_string(script))
+								.expect("failed to create word")
+								.contains(&ConceptOrWord::Word(word)),
+							"word string is wrong"
+						);
+					}
+				}
+			});
+		});
+	});
+}
@@ -0,0 +1,79 @@
+#![warn(clippy::nursery, clippy::pedantic, clippy::restriction)]
+#![allow(
+	clippy::indexing_slicing,
+	clippy::needless_return,
+	clippy::non_ascii_literal,
+	clippy::missing_inline_in_public_items,
+	clippy::option_expect_used,
+	clippy::panic,
+	clippy::result_expect_used,
+	clippy::shadow_reuse,
+	clippy::shadow_same
+)]
+
+use rayon::prelude::*;
+use uywi::{ConceptOrWord, Error, Length, Pages, Script};
+
+#[test]
+fn x_sampa() {
+	let script = Script::XSampa;
+
+	[Length::L1, Length::L2, Length::L3].into_par_iter().for_each(|length| {
+		Pages::new(*length).par_bridge().for_each(|page| {
+			page.into_iter().par_bridge().for_each(|row| {
+				for (position, concept) in row.enumerate() {
+					let string = concept.to_string(script);
+
+					assert!(string.is_ascii(), "concept string isn't ASCII");
+					assert!(
+						concept == script.from_concept(&string).expect("failed to create concept"),
+						"concept string is wrong"
+					);
+
+					// building words from strings is expensive, so we check all of `L1` and `L2` and one concept per row of `L3`
+					if *length == Length::L3 && position > 0 {
+						continue;
+					}
+
+					for word in concept.into_iter().flatten() {
+						let string = word.to_string(script);
+
+						assert!(string.is_ascii(), "word string isn't ASCII");
+						assert!(
+							script.parse_all(&string).expect("failed to create word")
+								== Script::IpaPeter
+									.parse_all(&word.to_string(Script::IpaPeter))
+									.expect("failed to create word"),
+							"word string is wrong"
+						);
+					}
+				}
+			});
+		});
+	});
+
+	let word = match Script::IpaPeter.from_str("xaħːɑ̃").expect("failed to create word").concept_or_word() {
+		ConceptOrWord::Word(word) => word,
+		ConceptOrWord::Concept(_) => panic!("word should be a word"),
+	};
+
+	assert!(word.to_string(script) == "xaX\\:A~", "word string is wrong");
+	assert!(
+		word.derivation(script).expect("failed to derive word").segments().concat() == word.to_string(script),
+		"derivation is wrong"
+	);
+	assert!(
+		word.syllables(script)
+			.expect("failed to find syllables")
+			.iter()
+			.map(|syllable| return syllable.range())
+			.collect::<Vec<_>>()
+			== [0..2, 2..7],
+		"syllables are wrong"
+	);
+	assert!(matches!(script.from_str("xaħːɑ̃"), Err(Error::WordInvalid)), "IPA should be invalid");
+	assert!(
+		matches!(script.from_concept("o"), Err(Error::ConceptRadicalInvalid)),
+		"radical should be invalid"
+	);
+}